@@ -0,0 +1,110 @@
+use super::{Measure, UnitList, UnitTrait};
+use crate::common::{Float, Number, RoundTo};
+/// The rules used to pick the most readable unit for a Measure.
+///
+/// By default the value is kept in `[1, 1000)`, using the largest unit that does so.
+pub struct BestUnitRule<'l, U: UnitTrait> {
+    /// The lowest value that is readable
+    min: Float,
+    /// The value past which a larger unit is used
+    max: Float,
+    /// Whether a whole number is worth going outside of the range for
+    whole: bool,
+    /// Units that are used first if they give a value in the range
+    preferred: Vec<&'l U>,
+}
+impl<'l, U: UnitTrait> BestUnitRule<'l, U> {
+    /// Generates the default rule (values in `[1, 1000)`)
+    pub fn new() -> Self {
+        Self {
+            min: 1.0,
+            max: 1000.0,
+            whole: false,
+            preferred: Vec::new(),
+        }
+    }
+    /// Keeps the value in `[min, max)` (when possible)
+    pub fn with_range(mut self, min: Float, max: Float) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+    /// Prefers a unit that gives a whole number of at least `min`, even past `max`.
+    ///
+    /// EX: `1500 m` stays in metres instead of becoming `1.5 km`.
+    pub fn prefer_whole(mut self) -> Self {
+        self.whole = true;
+        self
+    }
+    /// Uses the first of these units (in order) that gives a value in the range.
+    ///
+    /// If none do, the unit is picked from the whole list as usual.
+    pub fn with_preferred(mut self, preferred: Vec<&'l U>) -> Self {
+        self.preferred = preferred;
+        self
+    }
+    /// How far the value is outside of the range (`0` if inside)
+    fn distance(&self, val: Float) -> Float {
+        let val = val.abs();
+        if val == 0.0 || (val >= self.min && val < self.max) {
+            0.0
+        } else if val < self.min {
+            (self.min / val).log10()
+        } else {
+            (val / self.max).log10()
+        }
+    }
+}
+impl<'l, U: UnitTrait> Default for BestUnitRule<'l, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// Implement best unit selection
+impl<'l, U: UnitTrait, N: Number> Measure<'l, U, N> {
+    /// Picks the unit from the list that makes the value the most readable.
+    ///
+    /// EX: `0.0042 km` is best as `4.2 m`, and `15000 mm` as `15 m` (see `BestUnitRule`).
+    pub fn best_unit(&self, list: &UnitList<'l, U>, rule: &BestUnitRule<'l, U>) -> &'l U {
+        // The preferred units come first, if any of them fit
+        if let Some(unit) = rule
+            .preferred
+            .iter()
+            .find(|unit| rule.distance(self.get_val_as(unit).to_float()) == 0.0)
+        {
+            return unit;
+        }
+        let mut units = list.get_list().clone();
+        if units.is_empty() {
+            return self.get_unit();
+        }
+        // Largest unit first (the fewest of them in a base unit)
+        // A runtime unit with a NaN factor sorts last instead of panicking
+        units.sort_by(|a, b| a.in_base().total_cmp(&b.in_base()));
+        if rule.whole {
+            let whole = units.iter().find(|unit| {
+                let val = self.get_val_as(unit).to_float().abs().round_to(9);
+                val >= rule.min && val.fract() == 0.0
+            });
+            if let Some(unit) = whole {
+                return unit;
+            }
+        }
+        // Otherwise the largest unit that is closest to (or in) the range
+        let mut best = units[0];
+        let mut best_distance = rule.distance(self.get_val_as(best).to_float());
+        for &unit in &units[1..] {
+            let distance = rule.distance(self.get_val_as(unit).to_float());
+            if distance < best_distance {
+                best = unit;
+                best_distance = distance;
+            }
+        }
+        best
+    }
+    /// Returns this value in the unit picked by `best_unit`
+    pub fn to_best_unit(&self, list: &UnitList<'l, U>, rule: &BestUnitRule<'l, U>) -> Self {
+        let unit = self.best_unit(list, rule);
+        Measure::new(self.get_val_as(unit), unit)
+    }
+}
@@ -0,0 +1,65 @@
+use super::{Measure, UnitTrait};
+use crate::common::{Float, Number};
+/// How close two values must be to count as equal (see `Measure::approx_eq`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// The values may differ by this much (in the unit of the Measure compared against)
+    Absolute(Float),
+    /// The values may differ by this fraction of the larger value (ex: `1e-9`)
+    Relative(Float),
+    /// The values may be this many representable values apart, in the number type of the Measure.
+    ///
+    /// Exact types (ex: `Rational`) have no neighbouring values, so they must be equal.
+    Ulps(u64),
+}
+// Implement comparisons
+impl<'l, U: UnitTrait, N: Number> Measure<'l, U, N> {
+    /// Whether the other value is within the tolerance of this one.
+    ///
+    /// The other value is converted to this unit first, and the conversion may be a hair off,
+    /// so `1 ft` and `12 in` need a tolerance such as `Ulps(4)` or `Relative(1e-12)` to be equal.
+    /// `Absolute(0.0)` and `Ulps(0)` only allow values that convert exactly.
+    pub fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        let val = self.get_val();
+        let other = other.get_val_as(self.get_unit());
+        let (float, other_float) = (val.to_float(), other.to_float());
+        match tolerance {
+            Tolerance::Absolute(epsilon) => (float - other_float).abs() <= epsilon,
+            Tolerance::Relative(max_relative) => {
+                (float - other_float).abs() <= float.abs().max(other_float.abs()) * max_relative
+            }
+            Tolerance::Ulps(max_ulps) => val.ulps_apart(other) <= max_ulps,
+        }
+    }
+    /// Returns the smaller of the two values (in its own unit)
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+    /// Returns the larger of the two values (in its own unit)
+    pub fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+    /// Restricts the value to the range `[min, max]`.
+    ///
+    /// A value outside of the range is replaced by the bound (in the unit of the bound).
+    ///
+    /// Panics if `min` is greater than `max`, or either is NaN (like `f64::clamp`).
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "min > max, or either was NaN");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::Measure;
+use crate::base_types::UnitTrait;
+use crate::common::{decimal_to_rational, NotRational, Number, Rational};
+use std::convert::TryFrom;
+
+/// A Measure that stores its value as an exact ratio.
+///
+/// Conversions use the exact unit factors (see `UnitTrait::in_base_ratio`; ex: 1 in is exactly 0.0254 m),
+/// so round trips like in → m → in give back exactly the value they started with.
+pub type ExactMeasure<'l, U> = Measure<'l, U, Rational>;
+
+// The float is taken as the decimal it is written as (ex: `0.1` is exactly `1/10`).
+// NaN, infinity, and decimals past `i128` have no exact ratio.
+impl<'l, U: UnitTrait> TryFrom<Measure<'l, U>> for ExactMeasure<'l, U> {
+    type Error = NotRational;
+    fn try_from(measure: Measure<'l, U>) -> Result<Self, Self::Error> {
+        let val = decimal_to_rational(measure.get_val())?;
+        Ok(ExactMeasure::new(val, measure.get_unit()))
+    }
+}
+impl<'l, U: UnitTrait> ExactMeasure<'l, U> {
+    /// Returns the (rounded) floating point Measure of this value
+    #[deprecated(note = "use `to_number::<Float>()` instead")]
+    pub fn to_measure(&self) -> Measure<'l, U> {
+        Measure::new(self.get_val().to_float(), self.get_unit())
+    }
+}
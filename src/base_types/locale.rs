@@ -0,0 +1,430 @@
+use super::measure::write_padded;
+use super::{Measure, UnitList, UnitTrait};
+use crate::common::serial;
+use crate::common::{Float, Number, ParseError};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::fmt;
+/// The plural categories a language may tell apart (from the Unicode CLDR).
+///
+/// English only has `One` (`1 metre`) and `Other` (`2 metres`),
+/// while Polish has `One` (`1 metr`), `Few` (`2 metry`), `Many` (`5 metrów`) and `Other` (`1,5 metra`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    /// For no items (ex: Arabic `0`)
+    Zero,
+    /// For a single item (ex: English `1`)
+    One,
+    /// For a pair (ex: Arabic `2`)
+    Two,
+    /// For a few items (ex: Polish `2`-`4`)
+    Few,
+    /// For many items (ex: Polish `5`-`21`)
+    Many,
+    /// For everything else (ex: English `2`, Polish `1.5`)
+    Other,
+}
+/// How a language picks the plural category of a value (see `PluralCategory`).
+///
+/// Where a language tells fractions apart, shown decimals count even when they are `0`:
+/// `1,0` is a fraction, so it is `1,0 metra` in Polish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralRule {
+    /// `One` for exactly `1` (shown decimals or not, as plain `display` does: `1.0 metre`),
+    /// `Other` for everything else (English, German)
+    English,
+    /// `One` below `2` (so `0` and `1.5` as well), `Other` for everything else (French)
+    French,
+    /// `One` for `1`; `Few` for whole numbers ending in `2`-`4` (but not `12`-`14`);
+    /// `Many` for other whole numbers; `Other` for fractions (and decimals shown)
+    Polish,
+    /// `One` for whole numbers ending in `1` (but not `11`); `Few` for whole numbers ending in `2`-`4`
+    /// (but not `12`-`14`); `Many` for other whole numbers; `Other` for fractions (and decimals shown)
+    /// (Russian, Ukrainian)
+    Russian,
+    /// `Other` for everything (Japanese, Chinese)
+    Invariant,
+}
+impl PluralRule {
+    /// The plural category of the value as displayed: after rounding,
+    /// with this many decimals shown (ex: `1` for `1.0`; the CLDR `v` operand)
+    pub fn category(&self, val: Float, decimals: usize) -> PluralCategory {
+        let abs = val.abs();
+        let whole = decimals == 0 && abs.is_finite() && abs.fract() == 0.0;
+        match self {
+            PluralRule::English if val == 1.0 => PluralCategory::One,
+            PluralRule::English => PluralCategory::Other,
+            PluralRule::French if abs < 2.0 => PluralCategory::One,
+            PluralRule::French => PluralCategory::Other,
+            PluralRule::Polish | PluralRule::Russian if !whole => PluralCategory::Other,
+            PluralRule::Polish if abs == 1.0 => PluralCategory::One,
+            PluralRule::Russian if abs % 10.0 == 1.0 && abs % 100.0 != 11.0 => PluralCategory::One,
+            PluralRule::Polish | PluralRule::Russian => {
+                let last = abs % 10.0;
+                let last_two = abs % 100.0;
+                if (2.0..=4.0).contains(&last) && !(12.0..=14.0).contains(&last_two) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            PluralRule::Invariant => PluralCategory::Other,
+        }
+    }
+}
+/// A translated name of a unit for one plural category
+struct UnitName {
+    /// The type of the unit translated (ex: `LengthUnit`)
+    kind: TypeId,
+    /// The id of the unit translated (ex: `metre`, see `UnitTrait::get_id`)
+    id: String,
+    /// The plural category this name is for
+    category: PluralCategory,
+    /// The translated name (ex: `metrów`)
+    name: Cow<'static, str>,
+}
+/// How measures are written in a language: separators, plural rules and unit names.
+///
+/// Units are translated by their type and id (see `with_unit_name`),
+/// so the are and the Julian year (both `a`) get their own names;
+/// units without a translation keep their own singular and plural names.
+pub struct Locale {
+    /// The language tag (ex: `pl`)
+    tag: Cow<'static, str>,
+    /// Separates the whole number from the decimals (ex: `,` in `1,5`)
+    decimal_separator: char,
+    /// Separates the groups of three digits, if there is one (ex: `.` in `1.234`)
+    group_separator: Option<char>,
+    /// How the plural category of a value is picked
+    plural_rule: PluralRule,
+    /// The translated unit names
+    names: Vec<UnitName>,
+}
+impl Locale {
+    /// Generates a new locale written like plain `display`: `.` for decimals,
+    /// no digit groups and English plurals (so `1.0 metre`, the same as `display(1)`)
+    pub fn new(tag: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            tag: tag.into(),
+            decimal_separator: '.',
+            group_separator: None,
+            plural_rule: PluralRule::English,
+            names: Vec::new(),
+        }
+    }
+    /// English: `1,234.5 metres`
+    pub fn english() -> Self {
+        Self::new("en").with_group_separator(',')
+    }
+    /// German: `1.234,5` (with English plurals)
+    pub fn german() -> Self {
+        Self::new("de")
+            .with_decimal_separator(',')
+            .with_group_separator('.')
+    }
+    /// French: `1 234,5` (with a narrow no-break space) and French plurals
+    pub fn french() -> Self {
+        Self::new("fr")
+            .with_decimal_separator(',')
+            .with_group_separator('\u{202F}')
+            .with_plural_rule(PluralRule::French)
+    }
+    /// Polish: `1 234,5` (with a no-break space) and Polish plurals
+    pub fn polish() -> Self {
+        Self::new("pl")
+            .with_decimal_separator(',')
+            .with_group_separator('\u{A0}')
+            .with_plural_rule(PluralRule::Polish)
+    }
+    /// Russian: `1 234,5` (with a no-break space) and Russian plurals
+    pub fn russian() -> Self {
+        Self::new("ru")
+            .with_decimal_separator(',')
+            .with_group_separator('\u{A0}')
+            .with_plural_rule(PluralRule::Russian)
+    }
+    /// Sets the separator between the whole number and the decimals
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+    /// Sets the separator between groups of three digits
+    pub fn with_group_separator(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+    /// Sets how the plural category of a value is picked
+    pub fn with_plural_rule(mut self, rule: PluralRule) -> Self {
+        self.plural_rule = rule;
+        self
+    }
+    /// Adds the name of the unit (ex: `METRE`) for the plural category.
+    ///
+    /// A category without a name falls back to the `Other` name,
+    /// then to the unit's own singular (for `One`) or plural name.
+    pub fn with_unit_name<U: UnitTrait + 'static>(
+        mut self,
+        unit: &U,
+        category: PluralCategory,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.names.push(UnitName {
+            kind: TypeId::of::<U>(),
+            id: unit.get_id().to_string(),
+            category,
+            name: name.into(),
+        });
+        self
+    }
+    /// Returns the language tag
+    pub fn get_tag(&self) -> &str {
+        &self.tag
+    }
+    /// Returns the separator between the whole number and the decimals
+    pub fn get_decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+    /// Returns the separator between groups of three digits (if there is one)
+    pub fn get_group_separator(&self) -> Option<char> {
+        self.group_separator
+    }
+    /// Returns how the plural category of a value is picked
+    pub fn get_plural_rule(&self) -> PluralRule {
+        self.plural_rule
+    }
+    /// The name of the unit for the plural category (see `with_unit_name`)
+    pub fn unit_name<'u, U: UnitTrait + 'static>(
+        &'u self,
+        unit: &'u U,
+        category: PluralCategory,
+    ) -> &'u str {
+        let find = |category: PluralCategory| {
+            self.names
+                .iter()
+                .find(|name| Self::names_unit(name, unit) && name.category == category)
+                .map(|name| name.name.as_ref())
+        };
+        find(category)
+            .or_else(|| find(PluralCategory::Other))
+            .unwrap_or_else(|| match category {
+                PluralCategory::One => unit.get_singular(),
+                _ => unit.get_plural(),
+            })
+    }
+    /// Writes the number (as formatted by Rust, ex: `-1234.5`) with the separators of this locale
+    pub fn format_number(&self, val: &str) -> String {
+        let (sign, val) = match val.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", val),
+        };
+        let whole_len = val.chars().take_while(|c| c.is_ascii_digit()).count();
+        let (whole, rest) = val.split_at(whole_len);
+        let mut result = String::from(sign);
+        for (index, digit) in whole.chars().enumerate() {
+            if let Some(separator) = self.group_separator {
+                if index > 0 && (whole_len - index) % 3 == 0 {
+                    result.push(separator);
+                }
+            }
+            result.push(digit);
+        }
+        result.push_str(&rest.replacen('.', &self.decimal_separator.to_string(), 1));
+        result
+    }
+    /// Reads a number written with the separators of this locale (ex: `1.234,5` in German).
+    ///
+    /// Anything else is read as `common::serial::parse_number` does (ex: `1,5e3` or `3 1/2`).
+    pub fn parse_number(&self, val: &str) -> Option<Float> {
+        let chars: Vec<char> = val.chars().collect();
+        let mut normalized = String::new();
+        for (index, c) in chars.iter().copied().enumerate() {
+            if c == self.decimal_separator {
+                normalized.push('.');
+            } else if self.is_group_separator(c) && Self::starts_group(&chars, index) {
+                // Leave the digit group out
+            } else {
+                normalized.push(c);
+            }
+        }
+        serial::parse_number(&normalized)
+    }
+    /// Parses the string (ex: `1.234,5 m` or `5 metrów`) with the units of the list and returns:
+    /// - The floating point value
+    /// - The unit type for this value
+    ///
+    /// Units may be given by their translated names (in any case) or as in `UnitList::parse_str`.
+    /// Only single values are read (not compounds like `5 ft 11 in`).
+    ///
+    /// If not properly parsed, the `ParseError` says why.
+    pub fn try_parse_str<'l, U: UnitTrait + 'static>(
+        &self,
+        list: &UnitList<'l, U>,
+        test_val: &str,
+    ) -> Result<(Float, &'l U), ParseError> {
+        let test_val = test_val.trim();
+        if test_val.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let (val, unit_str) = test_val.split_at(Self::number_len(test_val));
+        let (val, unit_str) = (val.trim(), unit_str.trim());
+        if val.is_empty() {
+            return Err(ParseError::BadNumber(test_val.to_string()));
+        }
+        let unit = match self.find_translated(list, unit_str) {
+            Some(unit) => unit,
+            None => list.try_find_in_list(unit_str)?,
+        };
+        let val = self
+            .parse_number(val)
+            .ok_or_else(|| ParseError::BadNumber(val.to_string()))?;
+        Ok((val, unit))
+    }
+    /// Parses the string with the units of the list (see `try_parse_str`).
+    ///
+    /// If not properly parsed (or the unit is ambiguous), returns None.
+    pub fn parse_str<'l, U: UnitTrait + 'static>(
+        &self,
+        list: &UnitList<'l, U>,
+        test_val: &str,
+    ) -> Option<(Float, &'l U)> {
+        self.try_parse_str(list, test_val).ok()
+    }
+    /// Parses the string into a Measure (see `try_parse_str`)
+    pub fn try_parse_measure<'l, U: UnitTrait + 'static>(
+        &self,
+        list: &UnitList<'l, U>,
+        test_val: &str,
+    ) -> Result<Measure<'l, U>, ParseError> {
+        let (val, unit) = self.try_parse_str(list, test_val)?;
+        Ok(Measure::new(val, unit))
+    }
+    /// The unit of the list with the translated name (in any case)
+    fn find_translated<'l, U: UnitTrait + 'static>(
+        &self,
+        list: &UnitList<'l, U>,
+        unit_str: &str,
+    ) -> Option<&'l U> {
+        let unit_str = unit_str.to_lowercase();
+        list.get_list().iter().copied().find(|unit| {
+            self.names
+                .iter()
+                .any(|name| Self::names_unit(name, *unit) && name.name.to_lowercase() == unit_str)
+        })
+    }
+    /// Whether the translated name is for the unit
+    fn names_unit<U: UnitTrait + 'static>(name: &UnitName, unit: &U) -> bool {
+        name.kind == TypeId::of::<U>() && name.id == unit.get_id()
+    }
+    /// Whether the character separates digit groups (any space does, if the separator is a space)
+    fn is_group_separator(&self, c: char) -> bool {
+        match self.group_separator {
+            Some(separator) if separator.is_whitespace() => c.is_whitespace(),
+            Some(separator) => c == separator,
+            None => false,
+        }
+    }
+    /// Whether the separator at the index is followed by exactly three digits after a digit
+    fn starts_group(chars: &[char], index: usize) -> bool {
+        let digit = |index: usize| matches!(chars.get(index), Some(c) if c.is_ascii_digit());
+        index > 0
+            && digit(index - 1)
+            && (index + 1..index + 4).all(digit)
+            && !digit(index + 4)
+            && chars.get(index + 4) != Some(&'/')
+    }
+    /// The length in bytes of the number at the start of the string (before the unit)
+    fn number_len(val: &str) -> usize {
+        let mut chars = val.char_indices().peekable();
+        let mut previous = ' ';
+        while let Some((index, c)) = chars.next() {
+            // An `e` between digits is an exponent (ex: `1,5e3`)
+            let exponent = (c == 'e' || c == 'E')
+                && previous.is_ascii_digit()
+                && matches!(
+                    chars.peek(),
+                    Some((_, next)) if next.is_ascii_digit() || "+-−".contains(*next)
+                );
+            if !exponent && (c.is_alphabetic() || "'\"°".contains(c)) {
+                return index;
+            }
+            previous = c;
+        }
+        val.len()
+    }
+}
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+// Implement localized display
+impl<'l, U: UnitTrait + 'static, N: Number> Measure<'l, U, N> {
+    /// Displays the value with the abbreviation after it, with the separators of the locale.
+    ///
+    /// The value is rounded to the number of decimals (and will show trailing `0`)
+    /// EX: `1.234,50m` in German
+    pub fn display_abbr_in(&self, decimals: usize, locale: &Locale) -> String {
+        let val = self.get_val().round_to(decimals).to_float();
+        let val = locale.format_number(&format!("{:.*}", decimals, val));
+        format!("{}{}", val, self.get_unit().get_abbr())
+    }
+    /// Displays the value with the name for its plural category in the locale (and a space between).
+    ///
+    /// The value is rounded to the number of decimals (and will show trailing `0`)
+    /// EX: `1 metr`, `2 metry`, `5 metrów` or `1,5 metra` in Polish
+    pub fn display_in(&self, decimals: usize, locale: &Locale) -> String {
+        let val = self.get_val().round_to(decimals).to_float();
+        let category = locale.get_plural_rule().category(val, decimals);
+        let name = locale.unit_name(self.get_unit(), category);
+        let val = locale.format_number(&format!("{:.*}", decimals, val));
+        format!("{} {}", val, name)
+    }
+    /// Returns a formatter that displays the value in the locale.
+    ///
+    /// This takes the same flags as the `Display` of the Measure
+    /// (ex: `format!("{:#.1}", length.localized(&locale))` gives `1,5 metra` in Polish).
+    pub fn localized<'a>(&'a self, locale: &'a Locale) -> Localized<'a, 'l, U, N> {
+        Localized {
+            measure: self,
+            locale,
+        }
+    }
+}
+/// Displays a Measure in a locale (see `Measure::localized`)
+pub struct Localized<'a, 'l, U: UnitTrait, N: Number> {
+    /// The Measure displayed
+    measure: &'a Measure<'l, U, N>,
+    /// The locale to display it in
+    locale: &'a Locale,
+}
+impl<'a, 'l, U: UnitTrait + 'static, N: Number> fmt::Display for Localized<'a, 'l, U, N> {
+    /// Displays the value with the abbreviation after it (and a space between): `1.234,5 m`
+    ///
+    /// The precision rounds the value to that many decimals (`{:.2}` gives `1.234,50 m`).
+    /// The alternate flag uses the name for the plural category instead (`{:#}` gives `1.234,5 metra`).
+    /// The width, fill and alignment apply to the whole text (`{:>12}` gives `   1.234,5 m`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = match f.precision() {
+            Some(decimals) => self.measure.get_val().round_to(decimals),
+            None => self.measure.get_val(),
+        };
+        let text = match f.precision() {
+            Some(decimals) => format!("{:.*}", decimals, val.to_float()),
+            None => val.to_string(),
+        };
+        let unit = self.measure.get_unit();
+        let name = if f.alternate() {
+            // The decimals shown, whether set by the precision or not
+            let decimals = text.find('.').map_or(0, |dot| text.len() - dot - 1);
+            let category = self
+                .locale
+                .get_plural_rule()
+                .category(val.to_float(), decimals);
+            self.locale.unit_name(unit, category)
+        } else {
+            unit.get_abbr()
+        };
+        let text = format!("{} {}", self.locale.format_number(&text), name);
+        write_padded(f, &text)
+    }
+}
@@ -1,24 +1,31 @@
 use super::unit::UnitList;
-use crate::base_types::UnitTrait;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use crate::base_types::{LinearUnit, UnitTrait};
+use crate::common::ParseError;
+use std::fmt::{self, Write};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use crate::common::{Float, RoundTo};
+use crate::common::{Float, Number, RoundTo};
 
 /// Measure stores a measurable value.  
 ///
 /// The unit type is determined by the supplied struct implementing UnitTrait.
+/// The value is stored as a `Float`, unless another `Number` type is given
+/// (ex: `Measure<LengthUnit, f32>`).
 ///
 /// TODO: LOTS MORE DOCUMENTATION ON THIS
-pub struct Measure<'l, UnitVal: UnitTrait> {
+pub struct Measure<'l, UnitVal: UnitTrait, N: Number = Float> {
     unit: &'l UnitVal,
-    val: Float,
+    val: N,
 }
 // Implement creation methods
-impl<U: UnitTrait> Measure<'static, U> {
+impl<'l, U: UnitTrait, N: Number> Measure<'l, U, N> {
     /// Generates a new Measurement with the given value and unit
-    pub fn new(val: Float, unit: &'static U) -> Measure<U> {
+    pub fn new(val: N, unit: &'l U) -> Measure<'l, U, N> {
         Measure { unit, val }
     }
+}
+// Implement parsing (into a `Float`, see `to_number` for other types)
+impl<'l, U: UnitTrait> Measure<'l, U> {
     /// Parses the literal string for a Measurement (ex: `12m`).
     ///
     /// The unit is checked against every unit found in the system provided.
@@ -26,27 +33,53 @@ impl<U: UnitTrait> Measure<'static, U> {
     /// If there are multiple matches, the first one found is returned.
     /// The order of these should not be relied upon.
     ///
-    /// Returns a Length struct if the value was parsed correctly
-    pub fn from_literal(system: UnitList<'static, U>, val: &str) -> Option<Measure<'static, U>> {
+    /// Returns a Measure if the value was parsed correctly
+    pub fn from_literal(system: &UnitList<'l, U>, val: &str) -> Option<Measure<'l, U>> {
         // Parse the string
         let res = system.parse_str(val)?;
-        // If found, return a new Length
+        // If found, return a new Measure
         Some(Measure {
             val: res.0,
             unit: res.1,
         })
     }
+    /// Parses the literal string for a Measurement (ex: `12m`).
+    ///
+    /// Same as `from_literal`, but the `ParseError` says why parsing failed,
+    /// and multiple different matches are an `AmbiguousUnit`.
+    pub fn try_from_literal(
+        system: &UnitList<'l, U>,
+        val: &str,
+    ) -> Result<Measure<'l, U>, ParseError> {
+        // Parse the string
+        let res = system.try_parse_str(val)?;
+        // If found, return a new Measure
+        Ok(Measure {
+            val: res.0,
+            unit: res.1,
+        })
+    }
 }
-// Implement getters and add/subtract
-impl<U: UnitTrait> Measure<'static, U> {
+// Implement getters
+impl<'l, U: UnitTrait, N: Number> Measure<'l, U, N> {
     /// Returns the value in the current unit
-    pub fn get_val(&self) -> Float {
+    pub fn get_val(&self) -> N {
         self.val
     }
     /// Returns the currently stored unit
-    pub fn get_unit(&self) -> &'static U {
+    pub fn get_unit(&self) -> &'l U {
         self.unit
     }
+    /// Returns the same Measure with the value stored as another `Number` type (ex: `f32`).
+    ///
+    /// The value goes through a `Float`, so a `Rational` is taken as the decimal it is written as.
+    /// Returns `None` if the other type cannot hold the value (ex: NaN as a `Rational`).
+    pub fn to_number<M: Number>(&self) -> Option<Measure<'l, U, M>> {
+        M::from_float(self.val.to_float()).map(|val| Measure::new(val, self.unit))
+    }
+}
+// Implement add/subtract (only for units without an offset)
+impl<'l, U: LinearUnit, N: Number> Measure<'l, U, N> {
     /// Adds the other value to this one.  
     ///
     /// The conversion is done within this method.
@@ -59,33 +92,57 @@ impl<U: UnitTrait> Measure<'static, U> {
     pub fn subtract_other(&mut self, other: &Self) {
         self.val -= other.get_val_as(self.unit);
     }
+    /// Returns how many times the other value fits into this one.
+    ///
+    /// The result has no unit (ex: `1 km` to `250 m` is `4`).
+    pub fn ratio(&self, other: &Self) -> N {
+        self.val / other.get_val_as(self.unit)
+    }
 }
 // Implement conversion traits
-impl<U: UnitTrait> Measure<'static, U> {
+impl<'l, U: UnitTrait, N: Number> Measure<'l, U, N> {
     /// Returns the value stored in the specified unit (without mutating)
-    pub fn get_val_as(&self, unit: &U) -> Float {
+    pub fn get_val_as(&self, unit: &U) -> N {
         Self::convert(self.val, self.unit, unit)
     }
+    /// Returns the value stored in the specified unit, or `None` if it does not fit the number type
+    pub fn checked_get_val_as(&self, unit: &U) -> Option<N> {
+        Self::checked_convert(self.val, self.unit, unit)
+    }
     /// Convert the value from and to the given units
-    pub fn convert(val: Float, from: &U, to: &U) -> Float {
-        val / from.in_base() * to.in_base()
+    ///
+    /// The offset of each unit is accounted for (see `UnitTrait::offset`).
+    /// The factors are in the number type, so a `Rational` converts without any loss.
+    ///
+    /// Panics if the result does not fit the number type, which only an exact type can run into
+    /// (ex: a `Rational` past `i128`); use `checked_convert` to handle that instead.
+    pub fn convert(val: N, from: &U, to: &U) -> N {
+        Self::checked_convert(val, from, to)
+            .expect("The converted value does not fit the number type")
+    }
+    /// Convert the value from and to the given units, or `None` if it does not fit the number type
+    pub fn checked_convert(val: N, from: &U, to: &U) -> Option<N> {
+        val.checked_sub(from.offset_as()?)?
+            .checked_div(from.in_base_as()?)?
+            .checked_mul(to.in_base_as()?)?
+            .checked_add(to.offset_as()?)
     }
     /// Converts the value stored to the new unit and stores the unit
-    pub fn convert_to(&mut self, new_unit: &'static U) {
+    pub fn convert_to(&mut self, new_unit: &'l U) {
         let val = self.val;
         let from = self.unit;
         // Convert the value over
-        self.val = Self::convert(val, &from, new_unit);
+        self.val = Self::convert(val, from, new_unit);
         // Set the unit
         self.unit = new_unit;
     }
 }
 // Implement display traits
-impl<U: UnitTrait> Measure<'static, U> {
+impl<'l, U: UnitTrait, N: Number> Measure<'l, U, N> {
     /// The value is rounded to the number of decimals (and will show trailing `0`)
     /// value = 1.5, decimals = 2, unit = "METRE"; result = `1.50m`
     pub fn display_abbr(&self, decimals: usize) -> String {
-        let val = self.val.round_to(decimals);
+        let val = self.val.round_to(decimals).to_float();
         format!("{:.*}{}", decimals, val, self.unit.get_abbr())
     }
     /// Displays the value with the appropriate singular or plural name after it
@@ -93,7 +150,7 @@ impl<U: UnitTrait> Measure<'static, U> {
     /// If plural: `1.5 metres` or `0.75 metres`
     /// If singular, `1 metre`
     pub fn display(&self, decimals: usize) -> String {
-        let val = self.val.round_to(decimals);
+        let val = self.val.round_to(decimals).to_float();
         if val == 1.0 {
             self.display_singular(decimals)
         } else {
@@ -105,7 +162,7 @@ impl<U: UnitTrait> Measure<'static, U> {
     /// The plurality of the value is not considered (see Dim.display(decimals)).
     /// EX: `1.0 metres`, `1.5 metres`
     pub fn display_plural(&self, decimals: usize) -> String {
-        let val = self.val.round_to(decimals);
+        let val = self.val.round_to(decimals).to_float();
         format!("{:.*} {}", decimals, val, self.unit.get_plural())
     }
     /// Displays the value with the singular name after it (and a space between).
@@ -113,13 +170,172 @@ impl<U: UnitTrait> Measure<'static, U> {
     /// The plurality of the value is not considered (see Dim.display(decimals)).
     /// EX: `1.0 metre`, `1.5 metre`
     pub fn display_singular(&self, decimals: usize) -> String {
-        let val = self.val.round_to(decimals);
+        let val = self.val.round_to(decimals).to_float();
         format!("{:.*} {}", decimals, val, self.unit.get_singular())
     }
 }
 
+// Implement compound display (ex: `5 ft 11 in`)
+impl<'l, U: LinearUnit> Measure<'l, U> {
+    /// Splits the value into whole numbers of the larger units,
+    /// with the remainder in the smallest unit rounded to the number of decimals.
+    ///
+    /// The units may be given in any order (ex: the list of a `UnitList`).
+    /// Rounding carries over to the larger units, so this never gives `5 ft 12 in`.
+    /// Parts that are `0` are left out, unless everything is `0`.
+    /// Each part has the sign of the value.
+    /// Of units that are equivalent (ex: `METRE` and `METER`), only the first is used.
+    pub fn split_into_units(&self, units: &[&'l U], decimals: usize) -> Vec<Measure<'l, U>> {
+        // Keep the first of any equivalent units, so no unit is split into twice
+        let mut units = units
+            .iter()
+            .fold(Vec::new(), |mut kept: Vec<&'l U>, &unit| {
+                if !kept.iter().any(|k| k.is_equivalent(unit)) {
+                    kept.push(unit);
+                }
+                kept
+            });
+        // Largest unit first (the fewest of them in a base unit)
+        if units.is_empty() {
+            units.push(self.unit);
+        }
+        // A runtime unit with a NaN factor sorts last instead of panicking
+        units.sort_by(|a, b| a.in_base().total_cmp(&b.in_base()));
+        let smallest = units[units.len() - 1];
+        // Round everything in the smallest unit first, so the rounding can carry
+        let sign = if self.val.is_sign_negative() {
+            -1.0
+        } else {
+            1.0
+        };
+        let mut remaining = (self.get_val_as(smallest) * sign).round_to(decimals);
+        let mut parts = Vec::new();
+        for &unit in &units[..units.len() - 1] {
+            // How many of the smallest unit make one of this unit
+            let size = Self::convert(1.0, unit, smallest);
+            let mut count = (remaining / size).floor();
+            remaining = (remaining - count * size).round_to(decimals);
+            // The conversion factors may be a hair off, so carry what is left by hand
+            if remaining >= size.round_to(decimals) {
+                count += 1.0;
+                remaining = (remaining - size).round_to(decimals).max(0.0);
+            }
+            if count != 0.0 {
+                parts.push(Measure::new(count * sign, unit));
+            }
+        }
+        if remaining != 0.0 {
+            parts.push(Measure::new(remaining * sign, smallest));
+        } else if parts.is_empty() {
+            parts.push(Measure::new(0.0, smallest));
+        }
+        parts
+    }
+    /// Displays the value split across the units (see `split_into_units`),
+    /// each part with its abbreviation: `5 ft 11.2 in`
+    ///
+    /// Only the part in the smallest unit is shown with the number of decimals.
+    pub fn display_compound_abbr(&self, units: &[&'l U], decimals: usize) -> String {
+        self.display_compound_with(units, decimals, |part, _| part.unit.get_abbr())
+    }
+    /// Displays the value split across the units (see `split_into_units`),
+    /// each part with its singular or plural name: `5 feet 11.2 inches`
+    ///
+    /// Only the part in the smallest unit is shown with the number of decimals.
+    pub fn display_compound(&self, units: &[&'l U], decimals: usize) -> String {
+        self.display_compound_with(units, decimals, |part, val| {
+            if val == 1.0 {
+                part.unit.get_singular()
+            } else {
+                part.unit.get_plural()
+            }
+        })
+    }
+    /// Joins the parts of the split value, naming each part with `name`
+    fn display_compound_with<F>(&self, units: &[&'l U], decimals: usize, name: F) -> String
+    where
+        F: for<'p> Fn(&'p Measure<'l, U>, Float) -> &'p str,
+    {
+        let parts = self.split_into_units(units, decimals);
+        // Only the smallest unit holds a remainder with decimals
+        let smallest = if units.is_empty() {
+            self.unit.in_base()
+        } else {
+            units
+                .iter()
+                .map(|unit| unit.in_base())
+                .fold(0.0, Float::max)
+        };
+        parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                // Drop the sign on everything but the first part
+                let val = if index == 0 { part.val } else { part.val.abs() };
+                if part.unit.in_base() == smallest {
+                    format!("{:.*} {}", decimals, val, name(part, val.abs()))
+                } else {
+                    format!("{} {}", val, name(part, val.abs()))
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+// Implement the standard formatting
+impl<'l, U: UnitTrait, N: Number> fmt::Display for Measure<'l, U, N> {
+    /// Displays the value with the abbreviation after it (and a space between): `1.5 m`
+    ///
+    /// The precision rounds the value to that many decimals (`{:.2}` gives `1.50 m`).
+    /// The alternate flag uses the singular or plural name instead (`{:#}` gives `1.5 metres`).
+    /// The width, fill and alignment apply to the whole text (`{:>8}` gives `   1.5 m`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = match f.precision() {
+            Some(decimals) => self.val.round_to(decimals),
+            None => self.val,
+        };
+        let name = if !f.alternate() {
+            self.unit.get_abbr()
+        } else if val.to_float() == 1.0 {
+            self.unit.get_singular()
+        } else {
+            self.unit.get_plural()
+        };
+        let text = match f.precision() {
+            Some(decimals) => format!("{:.*} {}", decimals, val.to_float(), name),
+            None => format!("{} {}", val, name),
+        };
+        write_padded(f, &text)
+    }
+}
+/// Writes the text with the width, fill and alignment of the formatter (left aligned by default).
+///
+/// `Formatter::pad` would also cut the text down to the precision, which is the decimals here.
+pub(super) fn write_padded(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let len = text.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(text),
+    };
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
 // Add the various operators
-impl<U: UnitTrait> Add<Self> for Measure<'static, U> {
+impl<'l, U: LinearUnit, N: Number> Add<Self> for Measure<'l, U, N> {
     type Output = Self;
     fn add(self, other: Self) -> Self {
         let val = self.val + other.get_val_as(self.unit);
@@ -129,12 +345,12 @@ impl<U: UnitTrait> Add<Self> for Measure<'static, U> {
         }
     }
 }
-impl<U: UnitTrait> AddAssign<Self> for Measure<'static, U> {
+impl<'l, U: LinearUnit, N: Number> AddAssign<Self> for Measure<'l, U, N> {
     fn add_assign(&mut self, other: Self) {
         self.add_other(&other);
     }
 }
-impl<U: UnitTrait> Sub<Self> for Measure<'static, U> {
+impl<'l, U: LinearUnit, N: Number> Sub<Self> for Measure<'l, U, N> {
     type Output = Self;
     fn sub(self, other: Self) -> Self {
         let val = self.val - other.get_val_as(self.unit);
@@ -144,20 +360,81 @@ impl<U: UnitTrait> Sub<Self> for Measure<'static, U> {
         }
     }
 }
-impl<U: UnitTrait> SubAssign<Self> for Measure<'static, U> {
+impl<'l, U: LinearUnit, N: Number> SubAssign<Self> for Measure<'l, U, N> {
     fn sub_assign(&mut self, other: Self) {
         self.subtract_other(&other);
     }
 }
+// Dividing by the same dimension gives a plain ratio
+impl<'l, U: LinearUnit, N: Number> Div<Self> for Measure<'l, U, N> {
+    type Output = N;
+    fn div(self, other: Self) -> N {
+        self.ratio(&other)
+    }
+}
+
+// Add the scalar operators
+impl<'l, U: LinearUnit, N: Number> Mul<N> for Measure<'l, U, N> {
+    type Output = Self;
+    fn mul(self, scalar: N) -> Self {
+        Measure {
+            val: self.val * scalar,
+            unit: self.unit,
+        }
+    }
+}
+impl<'l, U: LinearUnit> Mul<Measure<'l, U>> for Float {
+    type Output = Measure<'l, U>;
+    fn mul(self, measure: Measure<'l, U>) -> Measure<'l, U> {
+        measure * self
+    }
+}
+impl<'l, U: LinearUnit, N: Number> MulAssign<N> for Measure<'l, U, N> {
+    fn mul_assign(&mut self, scalar: N) {
+        self.val *= scalar;
+    }
+}
+impl<'l, U: LinearUnit, N: Number> Div<N> for Measure<'l, U, N> {
+    type Output = Self;
+    fn div(self, scalar: N) -> Self {
+        Measure {
+            val: self.val / scalar,
+            unit: self.unit,
+        }
+    }
+}
+impl<'l, U: LinearUnit, N: Number> DivAssign<N> for Measure<'l, U, N> {
+    fn div_assign(&mut self, scalar: N) {
+        self.val /= scalar;
+    }
+}
+impl<'l, U: LinearUnit, N: Number> Neg for Measure<'l, U, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Measure {
+            val: -self.val,
+            unit: self.unit,
+        }
+    }
+}
 
 // Implement the equality operator
-impl<U: UnitTrait> std::cmp::PartialEq for Measure<'static, U> {
+//
+// This is exact, so float error can make equal amounts differ (see `approx_eq`).
+impl<'l, U: UnitTrait, N: Number> std::cmp::PartialEq for Measure<'l, U, N> {
     fn eq(&self, other: &Self) -> bool {
         self.val == other.get_val_as(self.unit)
     }
 }
+// Implement ordering (the other value is converted to this unit first)
+impl<'l, U: UnitTrait, N: Number> std::cmp::PartialOrd for Measure<'l, U, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.val.partial_cmp(&other.get_val_as(self.unit))
+    }
+}
 // Implement clone and copy
-impl<U: UnitTrait> Clone for Measure<'static, U> {
+#[allow(clippy::non_canonical_clone_impl)]
+impl<'l, U: UnitTrait, N: Number> Clone for Measure<'l, U, N> {
     fn clone(&self) -> Self {
         Self {
             val: self.val,
@@ -165,4 +442,4 @@ impl<U: UnitTrait> Clone for Measure<'static, U> {
         }
     }
 }
-impl<U: UnitTrait> Copy for Measure<'static, U> {}
+impl<'l, U: UnitTrait, N: Number> Copy for Measure<'l, U, N> {}
@@ -1,4 +1,18 @@
+mod best_unit;
+mod compare;
+#[cfg(feature = "rational")]
+mod exact_measure;
+mod locale;
 mod measure;
+mod prefix;
+mod registry;
 mod unit;
+pub use best_unit::BestUnitRule;
+pub use compare::Tolerance;
+#[cfg(feature = "rational")]
+pub use exact_measure::ExactMeasure;
+pub use locale::{Locale, Localized, PluralCategory, PluralRule};
 pub use measure::Measure;
-pub use unit::{UnitList, UnitTrait};
+pub use prefix::{Prefix, PrefixableUnit, PrefixedUnits, SI_PREFIXES};
+pub use registry::{RegisteredUnit, UnitRegistry};
+pub use unit::{CaseMatching, LinearUnit, UnitList, UnitTrait};
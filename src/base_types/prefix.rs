@@ -0,0 +1,250 @@
+use super::{UnitList, UnitTrait};
+use crate::common::{Float, ParseError};
+/// A metric prefix (ex: `kilo`), which scales a unit by a power of ten
+pub struct Prefix {
+    /// The symbol put in front of the abbreviation (ex: `k`)
+    symbol: &'static str,
+    /// The name put in front of the full name (ex: `kilo`)
+    name: &'static str,
+    /// The power of ten this prefix multiplies by
+    exponent: i32,
+    /// How many prefixed units make one of the unit (`10` to the power of `-exponent`)
+    scale: Float,
+}
+impl Prefix {
+    /// The symbol put in front of the abbreviation (ex: `k`)
+    pub fn get_symbol(&self) -> &'static str {
+        self.symbol
+    }
+    /// The name put in front of the full name (ex: `kilo`)
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+    /// The power of ten this prefix multiplies by (ex: `3`)
+    pub fn get_exponent(&self) -> i32 {
+        self.exponent
+    }
+    /// Returns the abbreviation with this prefix (ex: `m` becomes `km`)
+    pub fn prefix_abbr(&self, abbr: &str) -> String {
+        format!("{}{}", self.symbol, abbr)
+    }
+    /// Returns the name with this prefix (ex: `metre` becomes `kilometre`)
+    pub fn prefix_name(&self, name: &str) -> String {
+        format!("{}{}", self.name, name)
+    }
+    /// Scales `in_base` (see `UnitTrait::in_base`) for the prefixed unit
+    pub fn prefix_in_base(&self, in_base: Float) -> Float {
+        in_base * self.scale
+    }
+}
+
+/// Every SI prefix, from quecto (10⁻³⁰) to quetta (10³⁰)
+pub static SI_PREFIXES: [Prefix; 24] = [
+    Prefix {
+        symbol: "q",
+        name: "quecto",
+        exponent: -30,
+        scale: 1e30,
+    },
+    Prefix {
+        symbol: "r",
+        name: "ronto",
+        exponent: -27,
+        scale: 1e27,
+    },
+    Prefix {
+        symbol: "y",
+        name: "yocto",
+        exponent: -24,
+        scale: 1e24,
+    },
+    Prefix {
+        symbol: "z",
+        name: "zepto",
+        exponent: -21,
+        scale: 1e21,
+    },
+    Prefix {
+        symbol: "a",
+        name: "atto",
+        exponent: -18,
+        scale: 1e18,
+    },
+    Prefix {
+        symbol: "f",
+        name: "femto",
+        exponent: -15,
+        scale: 1e15,
+    },
+    Prefix {
+        symbol: "p",
+        name: "pico",
+        exponent: -12,
+        scale: 1e12,
+    },
+    Prefix {
+        symbol: "n",
+        name: "nano",
+        exponent: -9,
+        scale: 1e9,
+    },
+    Prefix {
+        symbol: "µ",
+        name: "micro",
+        exponent: -6,
+        scale: 1e6,
+    },
+    Prefix {
+        symbol: "m",
+        name: "milli",
+        exponent: -3,
+        scale: 1e3,
+    },
+    Prefix {
+        symbol: "c",
+        name: "centi",
+        exponent: -2,
+        scale: 1e2,
+    },
+    Prefix {
+        symbol: "d",
+        name: "deci",
+        exponent: -1,
+        scale: 1e1,
+    },
+    Prefix {
+        symbol: "da",
+        name: "deca",
+        exponent: 1,
+        scale: 1e-1,
+    },
+    Prefix {
+        symbol: "h",
+        name: "hecto",
+        exponent: 2,
+        scale: 1e-2,
+    },
+    Prefix {
+        symbol: "k",
+        name: "kilo",
+        exponent: 3,
+        scale: 1e-3,
+    },
+    Prefix {
+        symbol: "M",
+        name: "mega",
+        exponent: 6,
+        scale: 1e-6,
+    },
+    Prefix {
+        symbol: "G",
+        name: "giga",
+        exponent: 9,
+        scale: 1e-9,
+    },
+    Prefix {
+        symbol: "T",
+        name: "tera",
+        exponent: 12,
+        scale: 1e-12,
+    },
+    Prefix {
+        symbol: "P",
+        name: "peta",
+        exponent: 15,
+        scale: 1e-15,
+    },
+    Prefix {
+        symbol: "E",
+        name: "exa",
+        exponent: 18,
+        scale: 1e-18,
+    },
+    Prefix {
+        symbol: "Z",
+        name: "zetta",
+        exponent: 21,
+        scale: 1e-21,
+    },
+    Prefix {
+        symbol: "Y",
+        name: "yotta",
+        exponent: 24,
+        scale: 1e-24,
+    },
+    Prefix {
+        symbol: "R",
+        name: "ronna",
+        exponent: 27,
+        scale: 1e-27,
+    },
+    Prefix {
+        symbol: "Q",
+        name: "quetta",
+        exponent: 30,
+        scale: 1e-30,
+    },
+];
+
+/// Units that can be scaled by a metric prefix (ex: Metre into Kilometre)
+pub trait PrefixableUnit: UnitTrait {
+    /// Generates a new unit that is this unit with the prefix in front
+    fn with_prefix(&self, prefix: &Prefix) -> Self;
+}
+
+/// Every prefixed version of a base unit (ex: every SI prefix of the Metre).
+///
+/// The units are generated once, so they can be borrowed by a `Measure` like any other unit.
+pub struct PrefixedUnits<U: PrefixableUnit> {
+    /// The prefixes used, in the same order as the units
+    prefixes: &'static [Prefix],
+    /// The generated units
+    units: Vec<U>,
+}
+impl<U: PrefixableUnit> PrefixedUnits<U> {
+    /// Generates the unit for every SI prefix of the base unit
+    pub fn new(base: &U) -> Self {
+        Self::with_prefixes(base, &SI_PREFIXES)
+    }
+    /// Generates the unit for each of the given prefixes of the base unit
+    pub fn with_prefixes(base: &U, prefixes: &'static [Prefix]) -> Self {
+        let units = prefixes
+            .iter()
+            .map(|prefix| base.with_prefix(prefix))
+            .collect();
+        Self { prefixes, units }
+    }
+    /// Returns the unit generated for the given prefix symbol (ex: `k`)
+    pub fn get(&self, symbol: &str) -> Option<&U> {
+        let symbol = normalize_micro(symbol);
+        self.prefixes
+            .iter()
+            .position(|prefix| prefix.symbol == symbol)
+            .map(|index| &self.units[index])
+    }
+    /// Attempts to find the given string (ex: `µm` or `gigametres`) among the prefixed units.
+    ///
+    /// This can be the abbreviation, singular or plural.
+    pub fn find(&self, unit_str: &str) -> Option<&U> {
+        self.list().find_in_list(&normalize_micro(unit_str))
+    }
+    /// Parse the given string (ex: `12 µm`) into the value and the prefixed unit
+    pub fn parse_str(&self, test_val: &str) -> Option<(Float, &U)> {
+        self.try_parse_str(test_val).ok()
+    }
+    /// Parse the given string (ex: `12 µm`) into the value and the prefixed unit.
+    ///
+    /// If not properly parsed, the `ParseError` says why.
+    pub fn try_parse_str(&self, test_val: &str) -> Result<(Float, &U), ParseError> {
+        self.list().try_parse_str(&normalize_micro(test_val))
+    }
+    /// Returns a list of every prefixed unit (ex: to merge with another list)
+    pub fn list(&self) -> UnitList<'_, U> {
+        UnitList::new("SI Prefixed", self.units.iter().collect())
+    }
+}
+
+/// Swaps the Greek letter mu (μ) for the micro sign (µ) used by the prefix
+fn normalize_micro(val: &str) -> String {
+    val.replace('\u{3bc}', "\u{b5}")
+}
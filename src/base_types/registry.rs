@@ -0,0 +1,177 @@
+use super::{Measure, UnitList, UnitTrait};
+use crate::common::ParseError;
+use std::borrow::Cow;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+/// Every unit system of a dimension (ex: SI, US), for looking units up by name.
+///
+/// Each dimension has a global registry with the built-in systems (see `RegisteredUnit`),
+/// to which applications may add their own units and systems at startup.
+pub struct UnitRegistry<U: UnitTrait + Send + Sync + 'static> {
+    /// The registered systems, and every unit of them merged
+    systems: RwLock<Systems<U>>,
+}
+/// The contents of a `UnitRegistry`, behind its lock
+struct Systems<U: 'static> {
+    /// The registered systems, in the order they were registered
+    lists: Vec<(String, Vec<&'static U>)>,
+    /// Every registered unit (true duplicates only once), merged again after a change
+    merged: Option<Vec<&'static U>>,
+}
+impl<U: UnitTrait + Send + Sync + 'static> UnitRegistry<U> {
+    /// Generates an empty registry
+    pub fn new() -> Self {
+        Self {
+            systems: RwLock::new(Systems {
+                lists: Vec::new(),
+                merged: None,
+            }),
+        }
+    }
+    /// Generates a registry with the given systems
+    pub fn with_systems(systems: &[&UnitList<'static, U>]) -> Self {
+        let registry = Self::new();
+        for system in systems {
+            registry.register_system(system);
+        }
+        registry
+    }
+    /// Adds a copy of the system (the units themselves are shared)
+    pub fn register_system(&self, system: &UnitList<'static, U>) {
+        let title = system.get_title().to_string();
+        let units = system.get_list().clone();
+        let mut systems = self.write();
+        systems.merged = None;
+        systems.lists.push((title, units));
+    }
+    /// Adds the unit to the system with the given title.
+    ///
+    /// If there is no such system yet, it is created.
+    pub fn register_unit(&self, system: impl Into<Cow<'static, str>>, unit: &'static U) {
+        let system = system.into();
+        let mut systems = self.write();
+        systems.merged = None;
+        match systems.lists.iter_mut().find(|(title, _)| *title == system) {
+            Some((_, list)) => push_new(list, unit),
+            None => systems.lists.push((system.into_owned(), vec![unit])),
+        }
+    }
+    /// Adds a unit defined at runtime (ex: loaded from a config) to the system with the given title.
+    ///
+    /// The unit is kept for the rest of the program, like a built-in one, so it can be borrowed
+    /// without holding on to the registry; it is meant for units added once at startup.
+    pub fn register_owned_unit(&self, system: impl Into<Cow<'static, str>>, unit: U) {
+        self.register_unit(system, Box::leak(Box::new(unit)));
+    }
+    /// The titles of the registered systems
+    pub fn get_systems(&self) -> Vec<String> {
+        let systems = self.read();
+        systems
+            .lists
+            .iter()
+            .map(|(title, _)| title.clone())
+            .collect()
+    }
+    /// Every registered unit (true duplicates only once)
+    pub fn get_units(&self) -> UnitList<'static, U> {
+        if let Some(merged) = &self.read().merged {
+            return UnitList::new("All", merged.clone());
+        }
+        let mut systems = self.write();
+        let systems = &mut *systems;
+        let lists = &systems.lists;
+        let merged = systems.merged.get_or_insert_with(|| {
+            let mut merged = Vec::new();
+            for (_, list) in lists {
+                for &unit in list {
+                    push_new(&mut merged, unit);
+                }
+            }
+            merged
+        });
+        UnitList::new("All", merged.clone())
+    }
+    /// Every unit with the given abbreviation, name, symbol or alias, with the title of its system
+    pub fn find_all(&self, unit_str: &str) -> Vec<(String, &'static U)> {
+        let systems = self.read();
+        systems
+            .lists
+            .iter()
+            .filter_map(|(title, list)| {
+                let unit = UnitList::new("", list.clone()).find_in_list(unit_str)?;
+                Some((title.clone(), unit))
+            })
+            .collect()
+    }
+    /// Finds the unit with the given abbreviation, name, symbol or alias in any system.
+    ///
+    /// Different spellings of the same unit are fine (ex: `mm` in SI and SI_US),
+    /// but units of different sizes are an `AmbiguousUnit`.
+    pub fn lookup(&self, unit_str: &str) -> Result<&'static U, ParseError> {
+        self.get_units().try_find_in_list(unit_str)
+    }
+    /// Every abbreviation, name, symbol or alias found in several systems, with the titles of the systems.
+    ///
+    /// EX: `mm` is in both SI and SI_US.
+    pub fn ambiguities(&self) -> Vec<(String, Vec<String>)> {
+        let mut names: Vec<String> = Vec::new();
+        for unit in self.get_units().get_list() {
+            let unit_names = [unit.get_abbr(), unit.get_singular(), unit.get_plural()];
+            let aliases = unit.get_aliases().iter().map(|alias| alias.as_ref());
+            for name in unit_names
+                .iter()
+                .copied()
+                .chain(unit.get_symbol())
+                .chain(aliases)
+            {
+                if !names.iter().any(|known| known == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let systems: Vec<String> = self
+                    .find_all(&name)
+                    .into_iter()
+                    .map(|(system, _)| system)
+                    .collect();
+                if systems.len() > 1 {
+                    Some((name, systems))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+    /// Parses the string (ex: `12 mm`) with the units of every system.
+    ///
+    /// If not properly parsed, the `ParseError` says why.
+    pub fn try_parse_str(&self, val: &str) -> Result<Measure<'static, U>, ParseError> {
+        Measure::try_from_literal(&self.get_units(), val)
+    }
+    // A panic while the lock was held cannot leave the systems half-changed
+    // (the merged units are cleared first, and units are only ever added), so they are still usable
+    fn read(&self) -> RwLockReadGuard<'_, Systems<U>> {
+        self.systems.read().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn write(&self) -> RwLockWriteGuard<'_, Systems<U>> {
+        self.systems.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+/// Adds the unit to the list, unless it has a true duplicate (see `UnitTrait::is_identical`)
+fn push_new<U: UnitTrait>(list: &mut Vec<&'static U>, unit: &'static U) {
+    if !list.contains(&unit) {
+        list.push(unit);
+    }
+}
+impl<U: UnitTrait + Send + Sync + 'static> Default for UnitRegistry<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// Units with a global registry of the built-in systems (see `UnitRegistry`)
+pub trait RegisteredUnit: UnitTrait + Send + Sync + 'static {
+    /// The global registry for this dimension
+    fn registry() -> &'static UnitRegistry<Self>;
+}
@@ -1,13 +1,31 @@
-use crate::common::serial::SERIAL_REGEX;
-use crate::common::Float;
+use super::Measure;
+use crate::common::serial::{
+    self, compound_remainder, split_compound, SERIAL_REGEX, UNIT_SUFFIX_REGEX,
+};
+use crate::common::{Float, Number, ParseError};
+use std::borrow::Cow;
 /// The trait from which all units must derive
 pub trait UnitTrait: Sized + PartialEq {
+    /// What identifies this unit among the others of its kind (ex: `imperial gallon`).
+    ///
+    /// This is the singular unless a unit says otherwise.
+    fn get_id(&self) -> &str {
+        self.get_singular()
+    }
     /// The abbreviation for this unit
-    fn get_abbr(&self) -> &'static str;
+    fn get_abbr(&self) -> &str;
     /// Full name of this unit (singular)
-    fn get_singular(&self) -> &'static str;
+    fn get_singular(&self) -> &str;
     /// Plural name of this unit
-    fn get_plural(&self) -> &'static str;
+    fn get_plural(&self) -> &str;
+    /// Symbol written straight after the number (ex: `'` for feet), if there is one
+    fn get_symbol(&self) -> Option<&str> {
+        None
+    }
+    /// Other spellings accepted when parsing (ex: `mtr` or `ft.`)
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &[]
+    }
     /// How many of this unit does it take to make a base unit.
     ///
     /// Base Units:
@@ -15,57 +33,218 @@ pub trait UnitTrait: Sized + PartialEq {
     /// - area: square metre
     /// - volume: litre
     /// - mass: gram
-    /// - TODO: finish out these
+    /// - time: second
+    /// - temperature: kelvin
+    /// - velocity: metre per second
+    /// - information: byte
     fn in_base(&self) -> Float;
+    /// The exact `in_base()` as (numerator, denominator), if `in_base()` is rounded.
+    ///
+    /// Ex: `5000 / 127` for the inch, which is exactly `0.0254` metres.
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        None
+    }
+    /// The `in_base()` in the given number type.
+    ///
+    /// Types that can hold it use `in_base_ratio()` (ex: exactly `5000 / 127` for the inch).
+    /// Returns `None` if the number type cannot hold it (see `Number::from_factor`).
+    fn in_base_as<N: Number>(&self) -> Option<N> {
+        N::from_factor(self.in_base(), self.in_base_ratio())
+    }
+    /// The value of this unit at the zero of the base unit.
+    ///
+    /// A value in this unit is `base * in_base() + offset()`.
+    /// This is `0` for every unit except those with a shifted zero (Celsius, Fahrenheit).
+    fn offset(&self) -> Float {
+        0.0
+    }
+    /// The `offset()` in the given number type, if it can hold it
+    fn offset_as<N: Number>(&self) -> Option<N> {
+        N::from_float(self.offset())
+    }
+    /// Whether the other unit has the same magnitude (ex: `METRE` and `METER`).
+    ///
+    /// Equivalent units convert to each other without changing the value.
+    fn is_equivalent(&self, other: &Self) -> bool {
+        self.in_base() == other.in_base() && self.offset() == other.offset()
+    }
+    /// Whether the other unit is the same unit, going by `get_id()`.
+    ///
+    /// This is the identity used by `==` for the built-in units;
+    /// `METRE` and `METER` are equivalent, but not identical.
+    fn is_identical(&self, other: &Self) -> bool {
+        self.get_id() == other.get_id()
+    }
+}
+/// Marker for units that share their zero with the base unit (no offset).
+///
+/// Only measures of these units may be added to and subtracted from each other;
+/// adding two absolute temperatures is meaningless, so they do not implement this.
+pub trait LinearUnit: UnitTrait {}
+/// How the case of a unit string is compared when finding it in a `UnitList`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseMatching {
+    /// Everything must be in the exact case
+    Exact,
+    /// Abbreviations and symbols must be in the exact case (`mm` is not `Mm`),
+    /// but names and aliases may be in any case (`Feet`)
+    Names,
+    /// Like `Names`, but if nothing else matches, abbreviations may be in any case (`12 M`).
+    ///
+    /// An exact match always wins, so `Mm` and `mm` are still told apart.
+    Lenient,
 }
 /// A list of units for a particular system.
 ///
 /// This is for something like SI length units, to avoid overlap of abbreviated units.
 pub struct UnitList<'l, U: UnitTrait> {
     /// Title of these units
-    title: &'static str,
+    title: Cow<'static, str>,
     /// The list of stored units
     units: Vec<&'l U>,
+    /// How the case of a unit string is compared
+    case: CaseMatching,
 }
-impl<U: UnitTrait> UnitList<'static, U> {
+impl<'l, U: UnitTrait> UnitList<'l, U> {
     /// Generates a new UnitList with the given info
-    pub fn new(title: &'static str, units: Vec<&'static U>) -> Self {
-        Self { title, units }
+    ///
+    /// The units only need to live as long as the list (ex: units loaded at runtime).
+    pub fn new(title: impl Into<Cow<'static, str>>, units: Vec<&'l U>) -> Self {
+        Self {
+            title: title.into(),
+            units,
+            case: CaseMatching::Names,
+        }
+    }
+    /// Sets how the case of a unit string is compared (`CaseMatching::Names` by default)
+    pub fn with_case_matching(mut self, case: CaseMatching) -> Self {
+        self.case = case;
+        self
+    }
+    /// Returns how the case of a unit string is compared
+    pub fn get_case_matching(&self) -> CaseMatching {
+        self.case
     }
     /// Return the title of this set of units
     pub fn get_title(&self) -> &str {
-        self.title
+        &self.title
     }
     /// Grab the full list of units for this set
-    pub fn get_list(&self) -> &Vec<&'static U> {
+    pub fn get_list(&self) -> &Vec<&'l U> {
         &self.units
     }
     /// Grab the full list of units for this set
-    pub fn get_list_mut(&mut self) -> &mut Vec<&'static U> {
+    pub fn get_list_mut(&mut self) -> &mut Vec<&'l U> {
         &mut self.units
     }
     /// Parse the given string and returns:
     /// - The floating point value
     /// - The unit type for this value
     ///
-    /// If not properly parsed, returns None
-    pub fn parse_str(&self, test_val: &str) -> Option<(Float, &'static U)> {
+    /// If there are multiple matches for the unit, the first one found is returned
+    /// (see `find_in_list`).
+    ///
+    /// If not properly parsed, returns None.
+    /// See `try_parse_str` for the reason it failed.
+    pub fn parse_str(&self, test_val: &str) -> Option<(Float, &'l U)> {
+        let find = |unit_str: &str| {
+            self.find_in_list(unit_str)
+                .ok_or_else(|| ParseError::UnknownUnit(unit_str.to_string()))
+        };
+        self.parse_with(test_val, &find).ok()
+    }
+    /// Parse the given string and returns:
+    /// - The floating point value
+    /// - The unit type for this value
+    ///
+    /// Compound values (ex: `5 ft 11 in` or `5'11"`) are summed into the last unit given.
+    ///
+    /// If not properly parsed, the `ParseError` says why.
+    /// Unlike `parse_str`, several different matches for the unit are an `AmbiguousUnit`.
+    pub fn try_parse_str(&self, test_val: &str) -> Result<(Float, &'l U), ParseError> {
+        self.parse_with(test_val, &|unit_str: &str| self.try_find_in_list(unit_str))
+    }
+    /// Parses the string, finding each unit string with `find`
+    fn parse_with(
+        &self,
+        test_val: &str,
+        find: &dyn Fn(&str) -> Result<&'l U, ParseError>,
+    ) -> Result<(Float, &'l U), ParseError> {
+        if test_val.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
         // Grab the capture group
-        let captures = SERIAL_REGEX.captures(test_val)?;
+        let captures = match SERIAL_REGEX.captures(test_val) {
+            Some(captures) => captures,
+            None => return Self::try_parse_compound(test_val, find),
+        };
         // The value from the unit
         let val = captures.get(1).unwrap().as_str().trim();
         // The unit string
         let unit_str = captures.get(2).unwrap().as_str();
         // The resulting unit (if found)
-        let result = self.find_in_list(unit_str)?;
-        // Parse the number
-        let val = val
-            .replace(" ", "") // Remove the whitespace
-            .parse::<Float>() // Parse into a float
-            .expect(format!("Matched Str:{}", val).as_str()); // Yell if something bad happens
+        let result = find(unit_str)?;
+
+        Ok((Self::parse_number(val)?, result))
+    }
+    /// Parses a compound value (ex: `5 ft 11 in`) into the last unit given
+    fn try_parse_compound(
+        test_val: &str,
+        find: &dyn Fn(&str) -> Result<&'l U, ParseError>,
+    ) -> Result<(Float, &'l U), ParseError> {
+        let terms = match split_compound(test_val) {
+            Some(terms) => terms,
+            None => return Err(Self::explain_failure(test_val)),
+        };
+        let mut parsed = Vec::with_capacity(terms.len());
+        for (val, unit_str) in terms {
+            parsed.push((Self::parse_number(val)?, find(unit_str)?));
+        }
+        // Everything is summed in the last (normally smallest) unit
+        let unit = parsed[parsed.len() - 1].1;
+        let mut total: Float = parsed[1..]
+            .iter()
+            .map(|(val, from)| Measure::convert(*val, *from, unit))
+            .sum();
+        // The sign of the first term applies to the whole value
+        let (first, from) = parsed[0];
+        if first.is_sign_negative() {
+            total = -total;
+        }
+        total += Measure::convert(first, from, unit);
 
-        // Return the result (if this point is ever reached)
-        Some((val, result))
+        Ok((total, unit))
+    }
+    /// Parses the number of a serialized unit (ex: `- 12.5`, `1,250` or `3 1/2`)
+    fn parse_number(val: &str) -> Result<Float, ParseError> {
+        serial::parse_number(val).ok_or_else(|| ParseError::BadNumber(val.to_string()))
+    }
+    /// Works out why the string did not match `SERIAL_REGEX`
+    fn explain_failure(test_val: &str) -> ParseError {
+        let test_val = test_val.trim();
+        // Explain the term that breaks a compound (ex: `-11 in` of `5 ft -11 in`),
+        // as long as it starts like a number
+        if let Some(rest) = compound_remainder(test_val) {
+            if rest.starts_with(|c: char| "+-−.0123456789".contains(c)) {
+                return Self::explain_failure(rest);
+            }
+        }
+        match UNIT_SUFFIX_REGEX.find(test_val) {
+            // Something is off with the number in front of the unit
+            Some(unit) => {
+                let val = test_val[..unit.start()].trim();
+                if val.is_empty() {
+                    ParseError::BadNumber(test_val.to_string())
+                } else {
+                    ParseError::BadNumber(val.to_string())
+                }
+            }
+            // Whatever follows the number is not a unit
+            None => {
+                let unit = test_val.trim_start_matches(|c: char| "+-−.,/ 0123456789".contains(c));
+                ParseError::UnknownUnit(unit.to_string())
+            }
+        }
     }
     /// Attempts to find the given string in the list.
     ///
@@ -73,27 +252,70 @@ impl<U: UnitTrait> UnitList<'static, U> {
     /// - Abbreviation
     /// - Singular
     /// - Plural
+    /// - Symbol
+    /// - Alias
+    ///
+    /// The case is compared as set for the list (see `CaseMatching`).
     ///
     /// If not found, `None` is returned.
-    pub fn find_in_list(&self, unit_str: &str) -> Option<&'static U> {
-        // Loop through each unit
-        for unit in self.get_list() {
-            // If any of these cases is true, we have found the unit
-            if unit_str == unit.get_abbr()
-                || unit_str == unit.get_singular()
-                || unit_str == unit.get_plural()
-            {
-                return Some(&unit);
-            }
-            // Otherwise, keep looping
+    pub fn find_in_list(&self, unit_str: &str) -> Option<&'l U> {
+        self.find_all(unit_str).first().copied()
+    }
+    /// Attempts to find the given string in the list (see `find_in_list`).
+    ///
+    /// Unlike `find_in_list`, finding several different units is an error.
+    pub fn try_find_in_list(&self, unit_str: &str) -> Result<&'l U, ParseError> {
+        let found = self.find_all(unit_str);
+        let mut found = found.iter();
+        let result = match found.next() {
+            Some(unit) => *unit,
+            None => return Err(ParseError::UnknownUnit(unit_str.to_string())),
+        };
+        // Any other match must be the same unit (or another spelling of it)
+        if found.any(|unit| !unit.is_equivalent(result)) {
+            return Err(ParseError::AmbiguousUnit(unit_str.to_string()));
         }
-        // If nothing is found, return that
-        None
+        Ok(result)
+    }
+    /// Every unit matching the string, from the strictest comparison that finds any
+    fn find_all(&self, unit_str: &str) -> Vec<&'l U> {
+        let find = |matches: &dyn Fn(&U) -> bool| -> Vec<&'l U> {
+            self.get_list()
+                .iter()
+                .filter(|unit| matches(unit))
+                .copied()
+                .collect()
+        };
+        let exact = find(&|unit| {
+            Self::symbols(unit).any(|symbol| symbol == unit_str)
+                || Self::names(unit).any(|name| name == unit_str)
+        });
+        if !exact.is_empty() || self.case == CaseMatching::Exact {
+            return exact;
+        }
+        let unit_str = unit_str.to_lowercase();
+        let names = find(&|unit| Self::names(unit).any(|name| name.to_lowercase() == unit_str));
+        if !names.is_empty() || self.case == CaseMatching::Names {
+            return names;
+        }
+        find(&|unit| Self::symbols(unit).any(|symbol| symbol.to_lowercase() == unit_str))
+    }
+    /// The abbreviation and symbol of the unit, where the case may matter
+    fn symbols(unit: &U) -> impl Iterator<Item = &str> {
+        std::iter::once(unit.get_abbr()).chain(unit.get_symbol())
+    }
+    /// The names and aliases of the unit
+    fn names(unit: &U) -> impl Iterator<Item = &str> {
+        let aliases = unit.get_aliases().iter().map(|alias| alias.as_ref());
+        std::iter::once(unit.get_singular())
+            .chain(std::iter::once(unit.get_plural()))
+            .chain(aliases)
     }
     /// Merges the list of Unit Lists with this one.
     ///
-    /// Anything that evalutes to equal during merge is not added.
-    pub fn merge_other(&mut self, other: &Vec<&'static U>) {
+    /// Only true duplicates (see `UnitTrait::is_identical`) are not added,
+    /// so other spellings of a unit (ex: `meter`) are kept.
+    pub fn merge_other(&mut self, other: &Vec<&'l U>) {
         // Grab the list upon which we will operate
         let list = self.get_list_mut();
         for entry in other {
@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt;
+/// The reasons a serialized measure (ex: `12.5 m`) can fail to parse
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// Nothing (or only whitespace) was given
+    Empty,
+    /// The number could not be read (holds the offending text)
+    BadNumber(String),
+    /// The unit is not in the list searched (holds the offending text)
+    UnknownUnit(String),
+    /// The unit matches several different units in the list (holds the offending text)
+    AmbiguousUnit(String),
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "nothing to parse"),
+            ParseError::BadNumber(val) => write!(f, "invalid number '{}'", val),
+            ParseError::UnknownUnit(val) => write!(f, "unknown unit '{}'", val),
+            ParseError::AmbiguousUnit(val) => write!(f, "ambiguous unit '{}'", val),
+        }
+    }
+}
+impl Error for ParseError {}
@@ -11,9 +11,19 @@ impl RoundTo for Float {
         temp
     }
 }
-pub trait RoundTo {
+impl RoundTo for f32 {
     /// Returns a floating point number rounded to the specified digits
-    fn round_to(&self, decimals: usize) -> Float;
+    fn round_to(&self, decimals: usize) -> f32 {
+        let decimals: i32 = decimals as i32;
+        let mut temp = self * 10.0_f32.powi(decimals);
+        temp = temp.round();
+        temp /= 10.0_f32.powi(decimals);
+        temp
+    }
+}
+pub trait RoundTo {
+    /// Returns the number rounded to the specified digits
+    fn round_to(&self, decimals: usize) -> Self;
 }
 
 #[cfg(test)]
@@ -37,5 +47,7 @@ mod test {
         assert_eq!(val.round_to(3), 0.999);
         assert_eq!(val.round_to(4), 0.9995);
         assert_eq!(val.round_to(5), 0.99949);
+        let val: f32 = 0.99949;
+        assert_eq!(val.round_to(3), 0.999);
     }
 }
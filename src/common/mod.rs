@@ -1,3 +1,13 @@
+mod error;
 mod float;
+mod number;
+#[cfg(feature = "rational")]
+mod rational;
 pub mod serial;
+#[cfg(test)]
+pub(crate) mod test_util;
+pub use error::ParseError;
 pub use float::{Float, RoundTo};
+pub use number::Number;
+#[cfg(feature = "rational")]
+pub use rational::{decimal_to_rational, NotRational, Rational};
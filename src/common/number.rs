@@ -0,0 +1,100 @@
+use super::{Float, RoundTo};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+/// The numeric types a `Measure` can store its value in (ex: `f64`, `f32`, `Rational`).
+///
+/// The default is `Float`; implement this to store values in another type (ex: a decimal).
+pub trait Number:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Display
+    + RoundTo
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Converts from a `Float` (ex: a parsed value or `UnitTrait::offset`).
+    ///
+    /// Returns `None` if this type has no equivalent (ex: NaN as a `Rational`).
+    fn from_float(val: Float) -> Option<Self>;
+    /// Converts a unit factor (see `UnitTrait::in_base`).
+    ///
+    /// The exact ratio is given when the float is rounded, for the types that can hold it.
+    fn from_factor(float: Float, _ratio: Option<(i64, i64)>) -> Option<Self> {
+        Self::from_float(float)
+    }
+    /// Converts into a `Float` (ex: to compare the sizes of values)
+    fn to_float(self) -> Float;
+    /// How many representable values of this type lie between the two (see `Tolerance::Ulps`).
+    ///
+    /// Exact types have no neighbouring values, so this is `0` if equal and `u64::MAX` if not.
+    fn ulps_apart(self, other: Self) -> u64 {
+        if self == other {
+            0
+        } else {
+            u64::MAX
+        }
+    }
+    /// Adds the values, or `None` if the result does not fit this type.
+    ///
+    /// Floats go to infinity rather than overflow, so only exact types need to check.
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+    /// Subtracts the values, or `None` if the result does not fit this type
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(self - other)
+    }
+    /// Multiplies the values, or `None` if the result does not fit this type
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
+    /// Divides the values, or `None` if the result does not fit this type (or is a division by zero)
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Some(self / other)
+    }
+}
+impl Number for f64 {
+    fn from_float(val: Float) -> Option<Self> {
+        Some(val)
+    }
+    fn to_float(self) -> Float {
+        self
+    }
+    fn ulps_apart(self, other: Self) -> u64 {
+        if self == other {
+            // Covers `0.0` against `-0.0`
+            return 0;
+        }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return u64::MAX;
+        }
+        let (val, other) = (self.to_bits(), other.to_bits());
+        val.max(other) - val.min(other)
+    }
+}
+impl Number for f32 {
+    fn from_float(val: Float) -> Option<Self> {
+        Some(val as f32)
+    }
+    fn to_float(self) -> Float {
+        self.into()
+    }
+    fn ulps_apart(self, other: Self) -> u64 {
+        if self == other {
+            return 0;
+        }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return u64::MAX;
+        }
+        let (val, other) = (self.to_bits(), other.to_bits());
+        (val.max(other) - val.min(other)).into()
+    }
+}
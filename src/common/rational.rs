@@ -0,0 +1,143 @@
+use super::{Float, Number, RoundTo};
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use std::convert::TryFrom;
+use std::{error, fmt};
+/// The exact number type used for lossless conversions.
+///
+/// Values and unit factors must fit in an `i128` once reduced.
+pub type Rational = Ratio<i128>;
+/// The error when a float has no `Rational` equivalent.
+///
+/// This is NaN, either infinity, or a decimal past what an `i128` can hold (ex: `1e40` or `1e-40`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotRational(pub Float);
+impl fmt::Display for NotRational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} cannot be held as an exact ratio", self.0)
+    }
+}
+impl error::Error for NotRational {}
+/// Returns the exact decimal the float is written as (ex: `0.001` is exactly `1/1000`).
+///
+/// This uses the shortest representation that parses back to the same float,
+/// rather than the binary value stored (which `0.001` does not equal).
+pub fn decimal_to_rational(val: Float) -> Result<Rational, NotRational> {
+    if !val.is_finite() {
+        return Err(NotRational(val));
+    }
+    let text = val.to_string();
+    let (whole, fraction) = match text.find('.') {
+        Some(dot) => (&text[..dot], &text[dot + 1..]),
+        None => (&text[..], ""),
+    };
+    let numerator: i128 = format!("{}{}", whole, fraction)
+        .parse()
+        .map_err(|_| NotRational(val))?;
+    let denominator = 10_i128
+        .checked_pow(fraction.len() as u32)
+        .ok_or(NotRational(val))?;
+    Ok(Rational::new(numerator, denominator))
+}
+impl RoundTo for Rational {
+    /// Returns the ratio rounded to the specified digits (halves away from zero).
+    ///
+    /// Past what an `i128` can scale to (over 38 digits), the ratio is returned unrounded.
+    fn round_to(&self, decimals: usize) -> Rational {
+        let scale = match u32::try_from(decimals)
+            .ok()
+            .and_then(|digits| 10_i128.checked_pow(digits))
+        {
+            Some(scale) => Rational::from_integer(scale),
+            None => return *self,
+        };
+        CheckedMul::checked_mul(self, &scale)
+            .and_then(|scaled| CheckedDiv::checked_div(&scaled.round(), &scale))
+            .unwrap_or(*self)
+    }
+}
+impl Number for Rational {
+    /// The float is taken as the decimal it is written as (see `decimal_to_rational`)
+    fn from_float(val: Float) -> Option<Self> {
+        decimal_to_rational(val).ok()
+    }
+    fn from_factor(float: Float, ratio: Option<(i64, i64)>) -> Option<Self> {
+        match ratio {
+            Some((_, 0)) => None,
+            Some((numerator, denominator)) => {
+                Some(Rational::new(numerator.into(), denominator.into()))
+            }
+            None => decimal_to_rational(float).ok(),
+        }
+    }
+    fn to_float(self) -> Float {
+        *self.numer() as Float / *self.denom() as Float
+    }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        CheckedAdd::checked_add(&self, &other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        CheckedSub::checked_sub(&self, &other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        CheckedMul::checked_mul(&self, &other)
+    }
+    fn checked_div(self, other: Self) -> Option<Self> {
+        CheckedDiv::checked_div(&self, &other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decimal_to_rational, NotRational, Rational};
+    use crate::common::Number;
+    #[test]
+    fn test_decimal() {
+        assert_eq!(decimal_to_rational(0.001), Ok(Rational::new(1, 1000)));
+        assert_eq!(decimal_to_rational(-459.67), Ok(Rational::new(-45967, 100)));
+        assert_eq!(decimal_to_rational(12.0), Ok(Rational::from_integer(12)));
+        assert_eq!(
+            decimal_to_rational(1e-30),
+            Ok(Rational::new(1, 10_i128.pow(30)))
+        );
+        // Past the range of an `i128`
+        assert_eq!(decimal_to_rational(1e-40), Err(NotRational(1e-40)));
+        assert_eq!(decimal_to_rational(1e40), Err(NotRational(1e40)));
+        assert!(decimal_to_rational(f64::NAN).is_err());
+        assert!(decimal_to_rational(f64::INFINITY).is_err());
+        assert!(decimal_to_rational(f64::NEG_INFINITY).is_err());
+    }
+    #[test]
+    fn test_checked() {
+        let big = Rational::from_integer(i128::MAX);
+        assert_eq!(big.checked_add(Rational::from_integer(1)), None);
+        assert_eq!(big.checked_mul(Rational::from_integer(2)), None);
+        assert_eq!(
+            Rational::from_integer(1).checked_div(Rational::from_integer(0)),
+            None
+        );
+        assert_eq!(
+            Rational::new(1, 2).checked_add(Rational::new(1, 3)),
+            Some(Rational::new(5, 6))
+        );
+        assert_eq!(Rational::from_factor(1.0, Some((1, 0))), None);
+    }
+    #[test]
+    fn test_round() {
+        use crate::common::RoundTo;
+        assert_eq!(Rational::new(2, 3).round_to(2), Rational::new(67, 100));
+        assert_eq!(Rational::new(-1, 8).round_to(2), Rational::new(-13, 100));
+        assert_eq!(Rational::new(1, 8).round_to(0), Rational::from_integer(0));
+        let scale = 10_i128.pow(38);
+        assert_eq!(
+            Rational::new(1, 3).round_to(38),
+            Rational::new(scale / 3, scale)
+        );
+        // Too many digits to scale by in an `i128`
+        assert_eq!(Rational::new(1, 3).round_to(39), Rational::new(1, 3));
+        assert_eq!(
+            Rational::new(1, 3).round_to(usize::MAX),
+            Rational::new(1, 3)
+        );
+    }
+}
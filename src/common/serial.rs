@@ -1,16 +1,291 @@
+use crate::common::Float;
 use lazy_static;
 use regex::Regex;
+/// Pattern for the number of a serialized unit (without the sign).
+///
+/// In the order they are tried:
+/// - Fractions, after an optional whole number (`1/2`, `3 1/2`)
+/// - Vulgar fractions, after an optional whole number (`½`, `3½`)
+/// - Decimals, with optional digit-group separators and exponent (`1,250.5`, `1.5e3`)
+const NUMBER: &str = r"(?:\d+ +)?\d+/\d+|(?:\d+ *)?[¼½¾⅐⅑⅒⅓⅔⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]|(?:(?:\d{1,3}(?:,\d{3})+|\d+)(?:\.\d*)?|\.\d+)(?:e[+\-−]?\d+)?";
+/// Pattern for the sign of a serialized unit (`+`, `-` or the Unicode minus `−`)
+const SIGN: &str = r"[+\-−]";
+/// Pattern for the unit of a serialized unit.
+///
+/// Units may be several words separated by single spaces (`fl oz`) or slashes (`km/h`),
+/// may start with a degree sign (`°C`) or micro sign (`µm`), may end with a full stop (`ft.`)
+/// and may end with a power (`m²`, `m³`).
+/// A unit may also be a symbol straight after the number (`5'`, `11"`).
+const UNIT: &str = r#"°?[a-zµμ]+(?:[ /][a-z]+)*\.?[²³]?|['"]"#;
 lazy_static! {
     /// Regex to capture a serialized unit
     /// Capture groups are:
     /// 1. The value itself
     /// 2. The unit
-    pub static ref SERIAL_REGEX: Regex = Regex::new(r"(?i)^ *([+-]? *(?:\d*\.?\d+)|(?:\d+\.?\d*)) *([a-z]+) *$").unwrap();
+    pub static ref SERIAL_REGEX: Regex = Regex::new(&format!(r"(?i)^ *({}? *(?:{})) *({}) *$", SIGN, NUMBER, UNIT)).unwrap();
+    /// Regex to find the unit at the end of a string that failed `SERIAL_REGEX`.
+    ///
+    /// This is only used to explain why the string could not be parsed.
+    /// Capture groups are:
+    /// 1. The unit
+    pub static ref UNIT_SUFFIX_REGEX: Regex = Regex::new(&format!(r"(?i)({}) *$", UNIT)).unwrap();
+    /// Regex to capture the first term of a compound unit (ex: `-5 ft` of `-5 ft 11 in`)
+    /// Capture groups are:
+    /// 1. The value itself (with the sign for the whole compound)
+    /// 2. The unit
+    static ref FIRST_TERM_REGEX: Regex = Regex::new(&format!(r"(?i)^ *({}? *(?:{})) *({}) *,? *", SIGN, NUMBER, UNIT)).unwrap();
+    /// Regex to capture the other terms of a compound unit (ex: `11 in` of `-5 ft 11 in`)
+    /// Capture groups are:
+    /// 1. The value itself (no sign allowed)
+    /// 2. The unit
+    static ref NEXT_TERM_REGEX: Regex = Regex::new(&format!(r"(?i)^({}) *({}) *,? *", NUMBER, UNIT)).unwrap();
+}
+
+/// Splits a compound unit (ex: `5 ft 11 in` or `5'11"`) into its value and unit terms.
+///
+/// Only the first value may have a sign; it applies to the whole compound.
+/// Returns None unless the whole string is made of at least two terms.
+pub fn split_compound(val: &str) -> Option<Vec<(&str, &str)>> {
+    let (terms, rest) = take_terms(val);
+    if terms.len() < 2 || !rest.trim().is_empty() {
+        return None;
+    }
+    Some(terms)
+}
+
+/// The part of a compound unit that is not a term, after the terms before it.
+///
+/// EX: `-11 in` of `5 ft -11 in`, as only the first term may have a sign.
+/// Returns None if the string does not start with a term, or is only made of terms.
+pub fn compound_remainder(val: &str) -> Option<&str> {
+    let (terms, rest) = take_terms(val);
+    let rest = rest.trim();
+    if terms.is_empty() || rest.is_empty() {
+        return None;
+    }
+    Some(rest)
+}
+
+/// Reads as many terms of a compound unit as possible, returning them and whatever is left
+fn take_terms(val: &str) -> (Vec<(&str, &str)>, &str) {
+    let mut terms = Vec::new();
+    let mut rest = val;
+    while !rest.trim().is_empty() {
+        let regex: &Regex = if terms.is_empty() {
+            &FIRST_TERM_REGEX
+        } else {
+            &NEXT_TERM_REGEX
+        };
+        let captures = match regex.captures(rest) {
+            Some(captures) => captures,
+            None => break,
+        };
+        let num = captures.get(1).unwrap().as_str().trim();
+        let unit = captures.get(2).unwrap().as_str();
+        terms.push((num, unit));
+        rest = &rest[captures.get(0).unwrap().end()..];
+    }
+    (terms, rest)
+}
+
+/// A piece of a serialized number (see `tokenize_number`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberToken {
+    /// A sign; `true` if negative (`-` or the Unicode minus `−`)
+    Sign(bool),
+    /// A decimal number, with any digit-group separators removed (ex: `1250.5`, `1.5e3`)
+    Decimal(String),
+    /// A fraction, from a slash (`1/2`) or a vulgar fraction (`½`)
+    Fraction(u32, u32),
+}
+
+/// Splits the number of a serialized unit (ex: `- 3 1/2`) into its tokens.
+///
+/// Whitespace between the tokens is skipped.
+/// Returns None if anything is not part of a number.
+pub fn tokenize_number(val: &str) -> Option<Vec<NumberToken>> {
+    let chars: Vec<char> = val.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            c if c.is_whitespace() => index += 1,
+            '+' => {
+                tokens.push(NumberToken::Sign(false));
+                index += 1;
+            }
+            '-' | '−' => {
+                tokens.push(NumberToken::Sign(true));
+                index += 1;
+            }
+            '0'..='9' | '.' => {
+                let (decimal, end) = read_decimal(&chars, index)?;
+                // A slash straight after the digits makes it the top of a fraction
+                if chars.get(end) == Some(&'/') {
+                    let numerator = decimal.parse().ok()?;
+                    let digits = count_digits(&chars, end + 1);
+                    let denominator: String = chars[end + 1..end + 1 + digits].iter().collect();
+                    tokens.push(NumberToken::Fraction(numerator, denominator.parse().ok()?));
+                    index = end + 1 + digits;
+                } else {
+                    tokens.push(NumberToken::Decimal(decimal));
+                    index = end;
+                }
+            }
+            c => {
+                let (numerator, denominator) = vulgar_fraction(c)?;
+                tokens.push(NumberToken::Fraction(numerator, denominator));
+                index += 1;
+            }
+        }
+    }
+    Some(tokens)
+}
+
+/// Parses the number of a serialized unit (ex: `- 12.5`, `1,250`, `1.5e3`, `3 1/2` or `½`).
+///
+/// Returns None if it is not a number (or the denominator of a fraction is `0`).
+pub fn parse_number(val: &str) -> Option<Float> {
+    let tokens = tokenize_number(val)?;
+    let (negative, tokens) = match tokens.split_first() {
+        Some((NumberToken::Sign(negative), rest)) => (*negative, rest),
+        _ => (false, &tokens[..]),
+    };
+    let magnitude = match tokens {
+        [NumberToken::Decimal(decimal)] => decimal.parse::<Float>().ok()?,
+        [NumberToken::Fraction(numerator, denominator)] => fraction(*numerator, *denominator)?,
+        // A mixed number (ex: `3 1/2`) needs a whole number in front
+        [NumberToken::Decimal(whole), NumberToken::Fraction(numerator, denominator)]
+            if whole.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            whole.parse::<Float>().ok()? + fraction(*numerator, *denominator)?
+        }
+        _ => return None,
+    };
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Reads a decimal starting at `start`, returning it (without separators) and where it ends
+fn read_decimal(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let mut decimal = String::new();
+    let mut index = start;
+    let whole = count_digits(chars, index);
+    decimal.extend(&chars[index..index + whole]);
+    index += whole;
+    // Digit groups (ex: `1,250`) need exactly three digits after each separator
+    if (1..=3).contains(&whole) {
+        while chars.get(index) == Some(&',') && count_digits(chars, index + 1) == 3 {
+            decimal.extend(&chars[index + 1..index + 4]);
+            index += 4;
+        }
+    }
+    if chars.get(index) == Some(&'.') {
+        let fraction = count_digits(chars, index + 1);
+        decimal.extend(&chars[index..index + 1 + fraction]);
+        index += 1 + fraction;
+    }
+    if !decimal.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    // The exponent (ex: `e3`, `E-3` or `e−3`)
+    if let Some('e') | Some('E') = chars.get(index) {
+        let (sign, skip) = match chars.get(index + 1) {
+            Some('+') => ("", 2),
+            Some('-') | Some('−') => ("-", 2),
+            _ => ("", 1),
+        };
+        let digits = count_digits(chars, index + skip);
+        if digits > 0 {
+            decimal.push('e');
+            decimal.push_str(sign);
+            decimal.extend(&chars[index + skip..index + skip + digits]);
+            index += skip + digits;
+        }
+    }
+    Some((decimal, index))
+}
+
+/// How many ASCII digits are in a row starting at `start`
+fn count_digits(chars: &[char], start: usize) -> usize {
+    chars
+        .iter()
+        .skip(start)
+        .take_while(|c| c.is_ascii_digit())
+        .count()
+}
+
+/// The value of a fraction (None if the denominator is `0`)
+fn fraction(numerator: u32, denominator: u32) -> Option<Float> {
+    if denominator == 0 {
+        return None;
+    }
+    Some(Float::from(numerator) / Float::from(denominator))
+}
+
+/// The numerator and denominator of a vulgar fraction character (ex: `½`)
+fn vulgar_fraction(c: char) -> Option<(u32, u32)> {
+    let fraction = match c {
+        '¼' => (1, 4),
+        '½' => (1, 2),
+        '¾' => (3, 4),
+        '⅐' => (1, 7),
+        '⅑' => (1, 9),
+        '⅒' => (1, 10),
+        '⅓' => (1, 3),
+        '⅔' => (2, 3),
+        '⅕' => (1, 5),
+        '⅖' => (2, 5),
+        '⅗' => (3, 5),
+        '⅘' => (4, 5),
+        '⅙' => (1, 6),
+        '⅚' => (5, 6),
+        '⅛' => (1, 8),
+        '⅜' => (3, 8),
+        '⅝' => (5, 8),
+        '⅞' => (7, 8),
+        _ => return None,
+    };
+    Some(fraction)
 }
 
 #[cfg(test)]
 mod test {
-    use super::SERIAL_REGEX;
+    use super::{
+        compound_remainder, parse_number, split_compound, tokenize_number, NumberToken,
+        SERIAL_REGEX,
+    };
+    #[test]
+    fn test_compound() {
+        assert_eq!(
+            split_compound("5 ft 11 in"),
+            Some(vec![("5", "ft"), ("11", "in")])
+        );
+        assert_eq!(
+            split_compound("5'11\""),
+            Some(vec![("5", "'"), ("11", "\"")])
+        );
+        assert_eq!(
+            split_compound(" - 3 mi, 200 yd, 2.5 ft "),
+            Some(vec![("- 3", "mi"), ("200", "yd"), ("2.5", "ft")])
+        );
+        assert_eq!(
+            split_compound("1 m 20 cm"),
+            Some(vec![("1", "m"), ("20", "cm")])
+        );
+        // A single term is not a compound
+        assert_eq!(split_compound("5 ft"), None);
+        // Only the first term gets a sign
+        assert_eq!(split_compound("5 ft -11 in"), None);
+        assert_eq!(split_compound("5 ft 11"), None);
+        assert_eq!(split_compound("5 ft 11 in 3"), None);
+    }
+    #[test]
+    fn test_compound_remainder() {
+        assert_eq!(compound_remainder("5 ft -11 in"), Some("-11 in"));
+        assert_eq!(compound_remainder("5 ft 11"), Some("11"));
+        assert_eq!(compound_remainder("5 ft 11 in"), None);
+        assert_eq!(compound_remainder("ft 11 in"), None);
+    }
     #[test]
     fn test_regex() {
         let vals: Vec<TestVals> = vec![
@@ -57,10 +332,122 @@ mod test {
                 num: String::from("0.25"),
                 unit: String::from("AsDf"),
             },
+            // With a multi-word unit
+            TestVals {
+                val: String::from("8 fl oz  "),
+                num: String::from("8"),
+                unit: String::from("fl oz"),
+            },
+            // With a symbol for the unit
+            TestVals {
+                val: String::from("5'"),
+                num: String::from("5"),
+                unit: String::from("'"),
+            },
+            // With a compound unit
+            TestVals {
+                val: String::from("88 km/h"),
+                num: String::from("88"),
+                unit: String::from("km/h"),
+            },
+            // With a degree sign on the unit
+            TestVals {
+                val: String::from("-40 °F"),
+                num: String::from("-40"),
+                unit: String::from("°F"),
+            },
+            // With a power on the unit
+            TestVals {
+                val: String::from("2.5m³"),
+                num: String::from("2.5"),
+                unit: String::from("m³"),
+            },
+            // With an exponent
+            TestVals {
+                val: String::from("1.5e3 m"),
+                num: String::from("1.5e3"),
+                unit: String::from("m"),
+            },
+            // An `e` without digits is part of the unit
+            TestVals {
+                val: String::from("12em"),
+                num: String::from("12"),
+                unit: String::from("em"),
+            },
+            // With digit-group separators
+            TestVals {
+                val: String::from("1,250 ft"),
+                num: String::from("1,250"),
+                unit: String::from("ft"),
+            },
+            // With a mixed number
+            TestVals {
+                val: String::from("3 1/2 in"),
+                num: String::from("3 1/2"),
+                unit: String::from("in"),
+            },
+            // With a vulgar fraction
+            TestVals {
+                val: String::from("½ in"),
+                num: String::from("½"),
+                unit: String::from("in"),
+            },
+            // With the Unicode minus
+            TestVals {
+                val: String::from("−40 °C"),
+                num: String::from("−40"),
+                unit: String::from("°C"),
+            },
         ];
         // run the test
         run_test(vals);
     }
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize_number("- 3 1/2"),
+            Some(vec![
+                NumberToken::Sign(true),
+                NumberToken::Decimal(String::from("3")),
+                NumberToken::Fraction(1, 2),
+            ])
+        );
+        assert_eq!(
+            tokenize_number("1,250.5"),
+            Some(vec![NumberToken::Decimal(String::from("1250.5"))])
+        );
+        assert_eq!(
+            tokenize_number("1.5E−3"),
+            Some(vec![NumberToken::Decimal(String::from("1.5e-3"))])
+        );
+        assert_eq!(
+            tokenize_number("3½"),
+            Some(vec![
+                NumberToken::Decimal(String::from("3")),
+                NumberToken::Fraction(1, 2),
+            ])
+        );
+        assert_eq!(tokenize_number("12 m"), None);
+    }
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("- 12.5"), Some(-12.5));
+        assert_eq!(parse_number("1.5e3"), Some(1500.0));
+        assert_eq!(parse_number("1,250"), Some(1250.0));
+        assert_eq!(parse_number("1,234,567.5"), Some(1_234_567.5));
+        assert_eq!(parse_number("3 1/2"), Some(3.5));
+        assert_eq!(parse_number("3/4"), Some(0.75));
+        assert_eq!(parse_number("½"), Some(0.5));
+        assert_eq!(parse_number("2 ¾"), Some(2.75));
+        assert_eq!(parse_number("−40"), Some(-40.0));
+        // Digit groups need three digits
+        assert_eq!(parse_number("1,25"), None);
+        assert_eq!(parse_number("1/0"), None);
+        // Only whole numbers make mixed numbers
+        assert_eq!(parse_number("3.5 1/2"), None);
+        assert_eq!(parse_number("1 2"), None);
+        assert_eq!(parse_number("- -1"), None);
+    }
 
     fn run_test(vals: Vec<TestVals>) {
         for val in vals {
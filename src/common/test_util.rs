@@ -0,0 +1,27 @@
+use crate::base_types::{UnitList, UnitTrait};
+use crate::common::Float;
+/// Inputs that should all parse into the same value and unit
+pub struct TestStruct<U: 'static> {
+    /// A list of inputs that equal the output
+    pub inputs: Vec<&'static str>,
+    /// The output of each operation
+    pub output: Option<(Float, &'static U)>,
+}
+/// Parses each input with the list and checks it against the expected output
+pub fn run_test<U: UnitTrait>(list: &UnitList<'_, U>, val: TestStruct<U>) {
+    for input in val.inputs {
+        let res = list.parse_str(input);
+        match res {
+            Some(res) => {
+                let val = val.output.unwrap_or_else(|| panic!("Input:{}", input));
+                assert_eq!(val.0, res.0, "Input:{}", input);
+                // Ensure these match exactly
+                assert_eq!(val.1.get_abbr(), res.1.get_abbr());
+                assert_eq!(val.1.get_singular(), res.1.get_singular());
+                assert_eq!(val.1.get_plural(), res.1.get_plural());
+                assert_eq!(val.1.in_base(), res.1.in_base());
+            }
+            None => assert!(val.output.is_none(), "Value not found: {}", input),
+        }
+    }
+}
@@ -1,11 +1,29 @@
 mod base_types;
 pub mod common;
+/// Serde support for Measure (enabled with the `serde` feature)
+#[cfg(feature = "serde")]
+pub mod serialize;
 pub mod units;
 #[macro_use]
 extern crate lazy_static;
-pub use base_types::Measure;
+#[cfg(feature = "rational")]
+pub use base_types::ExactMeasure;
+pub use base_types::{BestUnitRule, Measure, Tolerance};
 /// Import this to create a new Unit type
 pub mod unit_creation {
-    pub use super::base_types::{UnitList, UnitTrait};
+    pub use super::base_types::{CaseMatching, LinearUnit, UnitList, UnitTrait};
+    pub use super::base_types::{Prefix, PrefixableUnit};
     pub use super::common::Float;
 }
+/// The registries of every unit system, for looking units up by name
+pub mod registry {
+    pub use super::base_types::{RegisteredUnit, UnitRegistry};
+}
+/// Metric prefixes, and the units generated from them (ex: `µm`, `Gm`)
+pub mod prefix {
+    pub use super::base_types::{Prefix, PrefixableUnit, PrefixedUnits, SI_PREFIXES};
+}
+/// Writing and reading measures in other languages (ex: `1.234,5 m`, `5 metrów`)
+pub mod locale {
+    pub use super::base_types::{Locale, Localized, PluralCategory, PluralRule};
+}
@@ -0,0 +1,144 @@
+use crate::base_types::{Measure, UnitList, UnitTrait};
+use crate::common::{Float, ParseError};
+use crate::units::length::{systems::ALL_LENGTH_LIST, LengthUnit};
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+#[cfg(test)]
+mod test_measure;
+
+/// Unit types with a list used to look up units when deserializing.
+///
+/// For other lists, deserialize through a `MeasureSeed`.
+pub trait DefaultUnitList: UnitTrait + 'static {
+    /// The list searched for the unit of a deserialized Measure
+    fn default_list() -> &'static UnitList<'static, Self>;
+    /// Parses the string form (ex: `"12.5 m"`).
+    ///
+    /// This should match `str::parse` for the unit type, so that the text and serde formats agree.
+    fn parse_default(val: &str) -> Result<Measure<'static, Self>, ParseError> {
+        Measure::try_from_literal(Self::default_list(), val)
+    }
+    /// Finds the unit of the struct form (ex: `"m"`)
+    fn find_default(unit_str: &str) -> Result<&'static Self, ParseError> {
+        Self::default_list().try_find_in_list(unit_str)
+    }
+}
+// Lengths fall back to the SI prefixes of the metre (ex: `"5 nm"`), the same as `str::parse`
+impl DefaultUnitList for LengthUnit {
+    fn default_list() -> &'static UnitList<'static, Self> {
+        &ALL_LENGTH_LIST
+    }
+    fn parse_default(val: &str) -> Result<Measure<'static, Self>, ParseError> {
+        val.parse()
+    }
+    fn find_default(unit_str: &str) -> Result<&'static Self, ParseError> {
+        LengthUnit::find_built_in(unit_str)
+    }
+}
+
+// Serialize as a string, in the same format that is parsed (ex: `"12.5 m"`)
+impl<'l, U: UnitTrait> Serialize for Measure<'l, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+// Deserialize from either the string or the struct form
+impl<'de, U: DefaultUnitList> Deserialize<'de> for Measure<'static, U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DefaultVisitor(PhantomData))
+    }
+}
+/// Deserializes a Measure with the lookups of `DefaultUnitList`
+struct DefaultVisitor<U>(PhantomData<U>);
+impl<'de, U: DefaultUnitList> Visitor<'de> for DefaultVisitor<U> {
+    type Value = Measure<'static, U>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a measure such as \"12.5 m\" or {{value, unit}}")
+    }
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        U::parse_default(val).map_err(E::custom)
+    }
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let (value, unit) = read_struct(map)?;
+        let unit = U::find_default(unit.trim()).map_err(de::Error::custom)?;
+        Ok(Measure::new(value, unit))
+    }
+}
+
+/// Serializes a Measure as a struct (ex: `{"value": 12.5, "unit": "m"}`) instead of a string.
+///
+/// Use with `#[serde(with = "dim_struct::serialize::as_struct")]`.
+pub mod as_struct {
+    use super::*;
+    /// Serializes the Measure as a struct with the value and the unit abbreviation
+    pub fn serialize<U: UnitTrait, S: Serializer>(
+        measure: &Measure<U>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Measure", 2)?;
+        state.serialize_field("value", &measure.get_val())?;
+        state.serialize_field("unit", measure.get_unit().get_abbr())?;
+        state.end()
+    }
+    /// Deserializes the Measure from either the string or the struct form
+    pub fn deserialize<'de, U: DefaultUnitList, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Measure<'static, U>, D::Error> {
+        Measure::deserialize(deserializer)
+    }
+}
+
+/// Deserializes a Measure, looking up the unit in the given list.
+///
+/// Both the string form (`"12.5 m"`) and the struct form (`{"value": 12.5, "unit": "m"}`) are accepted.
+pub struct MeasureSeed<'a, 'l, U: UnitTrait> {
+    /// The list searched for the unit
+    list: &'a UnitList<'l, U>,
+}
+impl<'a, 'l, U: UnitTrait> MeasureSeed<'a, 'l, U> {
+    /// Generates a new seed that searches the given list
+    pub fn new(list: &'a UnitList<'l, U>) -> Self {
+        Self { list }
+    }
+}
+impl<'de, 'a, 'l, U: UnitTrait> DeserializeSeed<'de> for MeasureSeed<'a, 'l, U> {
+    type Value = Measure<'l, U>;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+impl<'de, 'a, 'l, U: UnitTrait> Visitor<'de> for MeasureSeed<'a, 'l, U> {
+    type Value = Measure<'l, U>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a measure such as \"12.5 m\" or {{value, unit}}")
+    }
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        Measure::try_from_literal(self.list, val).map_err(E::custom)
+    }
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let (value, unit) = read_struct(map)?;
+        let unit = self
+            .list
+            .try_find_in_list(unit.trim())
+            .map_err(de::Error::custom)?;
+        Ok(Measure::new(value, unit))
+    }
+}
+/// Reads the value and unit of the struct form (ex: `{"value": 12.5, "unit": "m"}`)
+fn read_struct<'de, A: MapAccess<'de>>(mut map: A) -> Result<(Float, String), A::Error> {
+    let mut value: Option<Float> = None;
+    let mut unit: Option<String> = None;
+    while let Some(key) = map.next_key::<String>()? {
+        match key.as_str() {
+            "value" => value = Some(map.next_value()?),
+            "unit" => unit = Some(map.next_value()?),
+            _ => return Err(de::Error::unknown_field(&key, &["value", "unit"])),
+        }
+    }
+    let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+    let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+    Ok((value, unit))
+}
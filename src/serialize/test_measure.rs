@@ -0,0 +1,83 @@
+use super::{as_struct, MeasureSeed};
+use crate::base_types::UnitTrait;
+use crate::common::RoundTo;
+use crate::units::length::{systems::si::*, LengthUnit};
+use crate::units::mass::systems::us::{POUND, US_MASS_LIST};
+use crate::Measure;
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Config {
+    height: Measure<'static, LengthUnit>,
+    #[serde(with = "as_struct")]
+    width: Measure<'static, LengthUnit>,
+}
+
+#[test]
+fn string_form() {
+    let val = Measure::new(12.5, &METRE);
+    assert_eq!(serde_json::to_string(&val).unwrap(), "\"12.5 m\"");
+    let val: Measure<LengthUnit> = serde_json::from_str("\"3 ft\"").unwrap();
+    assert_eq!(val.get_val(), 3.0);
+    assert_eq!(val.get_unit().get_abbr(), "ft");
+    // The errors from the parser come through
+    let err = serde_json::from_str::<Measure<LengthUnit>>("\"3 furlongs\"")
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("unknown unit 'furlongs'"));
+}
+#[test]
+fn struct_form() {
+    let config = Config {
+        height: Measure::new(1.8, &METRE),
+        width: Measure::new(45.0, &CENTIMETRE),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"height":"1.8 m","width":{"value":45.0,"unit":"cm"}}"#
+    );
+    // Both forms are accepted for either field
+    let json = r#"{"height":{"value":6,"unit":"feet"},"width":"0.45 metres"}"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+    assert_eq!(config.height.get_val(), 6.0);
+    assert_eq!(config.height.get_unit().get_abbr(), "ft");
+    assert_eq!(config.width.get_val_as(&CENTIMETRE), 45.0);
+    let err = serde_json::from_str::<Config>(r#"{"height":{"value":6},"width":"1 m"}"#);
+    assert!(err
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("missing field `unit`"));
+}
+#[test]
+fn seed() {
+    // Any list can be used to find the unit
+    let mut json = serde_json::Deserializer::from_str("\"12 lb\"");
+    let val = MeasureSeed::new(&US_MASS_LIST)
+        .deserialize(&mut json)
+        .unwrap();
+    assert_eq!(val.get_val_as(&POUND), 12.0);
+    let mut json = serde_json::Deserializer::from_str(r#"{"unit":"oz","value":8}"#);
+    let val = MeasureSeed::new(&US_MASS_LIST)
+        .deserialize(&mut json)
+        .unwrap();
+    assert_eq!(val.get_val_as(&POUND).round_to(9), 0.5);
+}
+#[test]
+fn prefixed_round_trip() {
+    // Any SI prefix of the metre parses, so it deserializes as well
+    for text in ["5 nm", "2.5 µm", "3 Gm"] {
+        let val: Measure<LengthUnit> = text.parse().unwrap();
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, format!("\"{}\"", text));
+        let back: Measure<LengthUnit> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.get_val(), val.get_val());
+        assert!(back.get_unit() == val.get_unit());
+    }
+    let config: Config =
+        serde_json::from_str(r#"{"height":"5 nm","width":{"value":2.5,"unit":"µm"}}"#).unwrap();
+    assert_eq!(config.width.get_unit().get_singular(), "micrometre");
+    assert_eq!(config.height.get_val_as(&METRE).round_to(15), 5e-9);
+}
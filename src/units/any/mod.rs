@@ -0,0 +1,169 @@
+use super::area::AreaUnit;
+use super::information::InformationUnit;
+use super::length::LengthUnit;
+use super::mass::MassUnit;
+use super::temperature::TemperatureUnit;
+use super::time::TimeUnit;
+use super::velocity::VelocityUnit;
+use super::volume::VolumeUnit;
+use crate::common::serial::{split_compound, SERIAL_REGEX};
+use crate::common::ParseError;
+use crate::registry::RegisteredUnit;
+use crate::Measure;
+use std::fmt;
+use std::str::FromStr;
+#[cfg(test)]
+mod test_measure;
+/// The dimensions a value can be parsed into without knowing it in advance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    /// Ex: `3 ft`
+    Length,
+    /// Ex: `2 ha`
+    Area,
+    /// Ex: `5 L`
+    Volume,
+    /// Ex: `12 kg`
+    Mass,
+    /// Ex: `1.5 h`
+    Time,
+    /// Ex: `20 °C`
+    Temperature,
+    /// Ex: `60 mph`
+    Velocity,
+    /// Ex: `1.5 GiB`
+    Information,
+}
+/// A Measure of any dimension, found while parsing (see `AnyMeasure::parse`)
+#[derive(Clone, Copy, PartialEq)]
+pub enum AnyMeasure {
+    /// Ex: `3 ft`
+    Length(Measure<'static, LengthUnit>),
+    /// Ex: `2 ha`
+    Area(Measure<'static, AreaUnit>),
+    /// Ex: `5 L`
+    Volume(Measure<'static, VolumeUnit>),
+    /// Ex: `12 kg`
+    Mass(Measure<'static, MassUnit>),
+    /// Ex: `1.5 h`
+    Time(Measure<'static, TimeUnit>),
+    /// Ex: `20 °C`
+    Temperature(Measure<'static, TemperatureUnit>),
+    /// Ex: `60 mph`
+    Velocity(Measure<'static, VelocityUnit>),
+    /// Ex: `1.5 GiB`
+    Information(Measure<'static, InformationUnit>),
+}
+impl AnyMeasure {
+    /// Parses the string (ex: `12 kg`) with the units of every registered dimension.
+    ///
+    /// The dimension is the one whose registry has the unit (see `RegisteredUnit`).
+    /// A unit found in several dimensions (ex: `a` for are and annum) is an `AmbiguousUnit`.
+    pub fn parse(val: &str) -> Result<AnyMeasure, ParseError> {
+        let unit_str = match Self::find_unit_str(val) {
+            Some(unit_str) => unit_str,
+            // Let a registry work out what is wrong with it
+            None => {
+                return LengthUnit::registry()
+                    .try_parse_str(val)
+                    .map(AnyMeasure::Length)
+            }
+        };
+        let mut found = Dimension::ALL
+            .iter()
+            .filter(|dimension| dimension.has_unit(unit_str));
+        let dimension = match (found.next(), found.next()) {
+            (Some(dimension), None) => dimension,
+            (None, _) => return Err(ParseError::UnknownUnit(unit_str.to_string())),
+            (Some(_), Some(_)) => return Err(ParseError::AmbiguousUnit(unit_str.to_string())),
+        };
+        Ok(match dimension {
+            Dimension::Length => AnyMeasure::Length(LengthUnit::registry().try_parse_str(val)?),
+            Dimension::Area => AnyMeasure::Area(AreaUnit::registry().try_parse_str(val)?),
+            Dimension::Volume => AnyMeasure::Volume(VolumeUnit::registry().try_parse_str(val)?),
+            Dimension::Mass => AnyMeasure::Mass(MassUnit::registry().try_parse_str(val)?),
+            Dimension::Time => AnyMeasure::Time(TimeUnit::registry().try_parse_str(val)?),
+            Dimension::Temperature => {
+                AnyMeasure::Temperature(TemperatureUnit::registry().try_parse_str(val)?)
+            }
+            Dimension::Velocity => {
+                AnyMeasure::Velocity(VelocityUnit::registry().try_parse_str(val)?)
+            }
+            Dimension::Information => {
+                AnyMeasure::Information(InformationUnit::registry().try_parse_str(val)?)
+            }
+        })
+    }
+    /// The unit part of the string (the last unit of a compound value)
+    fn find_unit_str(val: &str) -> Option<&str> {
+        match SERIAL_REGEX.captures(val) {
+            Some(captures) => Some(captures.get(2).unwrap().as_str()),
+            None => split_compound(val)?.last().map(|(_, unit_str)| *unit_str),
+        }
+    }
+    /// Returns the dimension of the value
+    pub fn dimension(&self) -> Dimension {
+        match self {
+            AnyMeasure::Length(_) => Dimension::Length,
+            AnyMeasure::Area(_) => Dimension::Area,
+            AnyMeasure::Volume(_) => Dimension::Volume,
+            AnyMeasure::Mass(_) => Dimension::Mass,
+            AnyMeasure::Time(_) => Dimension::Time,
+            AnyMeasure::Temperature(_) => Dimension::Temperature,
+            AnyMeasure::Velocity(_) => Dimension::Velocity,
+            AnyMeasure::Information(_) => Dimension::Information,
+        }
+    }
+}
+impl Dimension {
+    /// Every dimension, in the order they are checked
+    pub const ALL: [Dimension; 8] = [
+        Dimension::Length,
+        Dimension::Area,
+        Dimension::Volume,
+        Dimension::Mass,
+        Dimension::Time,
+        Dimension::Temperature,
+        Dimension::Velocity,
+        Dimension::Information,
+    ];
+    /// Whether the registry of this dimension has the unit (see `UnitList::find_in_list`)
+    pub fn has_unit(&self, unit_str: &str) -> bool {
+        match self {
+            Dimension::Length => has_unit::<LengthUnit>(unit_str),
+            Dimension::Area => has_unit::<AreaUnit>(unit_str),
+            Dimension::Volume => has_unit::<VolumeUnit>(unit_str),
+            Dimension::Mass => has_unit::<MassUnit>(unit_str),
+            Dimension::Time => has_unit::<TimeUnit>(unit_str),
+            Dimension::Temperature => has_unit::<TemperatureUnit>(unit_str),
+            Dimension::Velocity => has_unit::<VelocityUnit>(unit_str),
+            Dimension::Information => has_unit::<InformationUnit>(unit_str),
+        }
+    }
+}
+/// Whether the registry of the unit type has the unit
+fn has_unit<U: RegisteredUnit>(unit_str: &str) -> bool {
+    U::registry().get_units().find_in_list(unit_str).is_some()
+}
+// Display the value the same as the typed Measure (ex: `12 kg`)
+impl fmt::Display for AnyMeasure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyMeasure::Length(val) => val.fmt(f),
+            AnyMeasure::Area(val) => val.fmt(f),
+            AnyMeasure::Volume(val) => val.fmt(f),
+            AnyMeasure::Mass(val) => val.fmt(f),
+            AnyMeasure::Time(val) => val.fmt(f),
+            AnyMeasure::Temperature(val) => val.fmt(f),
+            AnyMeasure::Velocity(val) => val.fmt(f),
+            AnyMeasure::Information(val) => val.fmt(f),
+        }
+    }
+}
+// Parse a value of any dimension (ex: `"5 L".parse()`)
+impl FromStr for AnyMeasure {
+    type Err = ParseError;
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        AnyMeasure::parse(val)
+    }
+}
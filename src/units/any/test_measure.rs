@@ -0,0 +1,61 @@
+use super::{AnyMeasure, Dimension};
+use crate::common::{ParseError, RoundTo};
+use crate::units::{length, mass, volume};
+
+#[test]
+fn parse() {
+    let val = AnyMeasure::parse("12 kg").unwrap();
+    assert_eq!(val.dimension(), Dimension::Mass);
+    match val {
+        AnyMeasure::Mass(val) => assert_eq!(val.get_val_as(&mass::systems::si::GRAM), 12000.0),
+        _ => panic!("Not a mass: {}", val),
+    }
+    let val: AnyMeasure = "3 ft".parse().unwrap();
+    assert_eq!(val.dimension(), Dimension::Length);
+    if let AnyMeasure::Length(val) = val {
+        let metres = val.get_val_as(&length::systems::si::METRE);
+        assert_eq!(metres.round_to(4), 0.9144);
+    }
+    let val = AnyMeasure::parse("5 L").unwrap();
+    assert_eq!(val.dimension(), Dimension::Volume);
+    assert!(val == AnyMeasure::Volume(crate::Measure::new(5.0, &volume::systems::si::LITRE)));
+    assert_eq!(val.to_string(), "5 L");
+    // Each dimension is found
+    let dimensions: Vec<Dimension> = ["2 ha", "1.5 h", "20 °C", "60 mph", "1.5 GiB", "5 ft 11 in"]
+        .iter()
+        .map(|val| AnyMeasure::parse(val).unwrap().dimension())
+        .collect();
+    assert_eq!(
+        dimensions,
+        vec![
+            Dimension::Area,
+            Dimension::Time,
+            Dimension::Temperature,
+            Dimension::Velocity,
+            Dimension::Information,
+            Dimension::Length,
+        ]
+    );
+}
+#[test]
+fn parse_errors() {
+    assert_eq!(AnyMeasure::parse("  ").err(), Some(ParseError::Empty));
+    assert_eq!(
+        AnyMeasure::parse("12 furlongs").err(),
+        Some(ParseError::UnknownUnit(String::from("furlongs")))
+    );
+    assert_eq!(
+        AnyMeasure::parse("1..2 kg").err(),
+        Some(ParseError::BadNumber(String::from("1..2")))
+    );
+    // Both the are (area) and the annum (time)
+    assert_eq!(
+        AnyMeasure::parse("3 a").err(),
+        Some(ParseError::AmbiguousUnit(String::from("a")))
+    );
+    // The compound must all be the one dimension
+    assert_eq!(
+        AnyMeasure::parse("5 kg 11 in").err(),
+        Some(ParseError::UnknownUnit(String::from("kg")))
+    );
+}
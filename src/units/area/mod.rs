@@ -0,0 +1,104 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use std::borrow::Cow;
+/// The different pre-made unit systems for Area
+pub mod systems;
+#[cfg(test)]
+mod test_measure;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for an Area entry.
+///
+/// The base unit for Area is `Square Metre`.
+pub struct AreaUnit {
+    /// Identifies this unit among the others of its kind (ex: `acre`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `sq ft`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many of this unit does it take to make a square metre
+    in_square_metre: Float,
+    /// The exact `in_square_metre` as (numerator, denominator), when `in_square_metre` is rounded
+    exact: Option<(i64, i64)>,
+}
+impl AreaUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `AreaUnit::new("tsubo", "tsubo", "tsubo", 1.0 / 3.305785)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_square_metre: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_square_metre,
+            exact: None,
+        }
+    }
+    /// Sets the exact `in_square_metre` as a ratio (ex: `25000000 / 16129` for the square inch), for exact conversions
+    pub fn with_exact(mut self, numerator: i64, denominator: i64) -> Self {
+        self.exact = Some((numerator, denominator));
+        self
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `sq ft` for square feet)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for AreaUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_square_metre
+    }
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        self.exact
+    }
+}
+impl LinearUnit for AreaUnit {}
+// Register the built-in systems
+impl RegisteredUnit for AreaUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::AREA_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for AreaUnit {
+    fn eq(&self, other: &AreaUnit) -> bool {
+        self.is_identical(other)
+    }
+}
@@ -0,0 +1,32 @@
+use super::super::AreaUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+/// The are (100 square metres)
+pub static ARE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("are"),
+    abbr: Cow::Borrowed("a"),
+    singular: Cow::Borrowed("are"),
+    plural: Cow::Borrowed("ares"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 0.01,
+    exact: None,
+};
+
+/// The hectare (100 ares)
+pub static HECTARE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("hectare"),
+    abbr: Cow::Borrowed("ha"),
+    singular: Cow::Borrowed("hectare"),
+    plural: Cow::Borrowed("hectares"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 0.0001,
+    exact: None,
+};
+
+lazy_static! {
+    /// The list of land Area units are stored here
+    pub static ref LAND_LIST: UnitList<'static, AreaUnit> = UnitList::new(
+        "Land",
+        vec!(&ARE, &HECTARE,),
+    );
+}
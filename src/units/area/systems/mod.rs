@@ -0,0 +1,17 @@
+use super::AreaUnit;
+use crate::registry::UnitRegistry;
+/// Module with land measurement units (Hectare and such)
+pub mod land;
+/// Module with SI units (Square Metre and such)
+pub mod si;
+/// Module with the United States customary units (Square Foot and such)
+pub mod us;
+
+lazy_static! {
+    /// Every built-in Area system, for looking units up by name
+    pub static ref AREA_REGISTRY: UnitRegistry<AreaUnit> = UnitRegistry::with_systems(&[
+        &si::SI_AREA_LIST,
+        &us::US_AREA_LIST,
+        &land::LAND_LIST,
+    ]);
+}
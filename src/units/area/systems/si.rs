@@ -0,0 +1,54 @@
+use super::super::AreaUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static SQUARE_MILLIMETRE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square millimetre"),
+    abbr: Cow::Borrowed("mm²"),
+    singular: Cow::Borrowed("square millimetre"),
+    plural: Cow::Borrowed("square millimetres"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1000000.0,
+    exact: None,
+};
+
+pub static SQUARE_CENTIMETRE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square centimetre"),
+    abbr: Cow::Borrowed("cm²"),
+    singular: Cow::Borrowed("square centimetre"),
+    plural: Cow::Borrowed("square centimetres"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 10000.0,
+    exact: None,
+};
+
+pub static SQUARE_METRE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square metre"),
+    abbr: Cow::Borrowed("m²"),
+    singular: Cow::Borrowed("square metre"),
+    plural: Cow::Borrowed("square metres"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1.0,
+    exact: None,
+};
+
+pub static SQUARE_KILOMETRE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square kilometre"),
+    abbr: Cow::Borrowed("km²"),
+    singular: Cow::Borrowed("square kilometre"),
+    plural: Cow::Borrowed("square kilometres"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 0.000001,
+    exact: None,
+};
+
+lazy_static! {
+    pub static ref SI_AREA_LIST: UnitList<'static, AreaUnit> = UnitList::new(
+        "SI",
+        vec!(
+            &SQUARE_MILLIMETRE,
+            &SQUARE_CENTIMETRE,
+            &SQUARE_METRE,
+            &SQUARE_KILOMETRE,
+        ),
+    );
+}
@@ -0,0 +1,60 @@
+use super::super::AreaUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static SQUARE_INCH: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square inch"),
+    abbr: Cow::Borrowed("in²"),
+    singular: Cow::Borrowed("square inch"),
+    plural: Cow::Borrowed("square inches"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1.0 / 0.00064516,
+    exact: Some((25000000, 16129)),
+};
+
+pub static SQUARE_FOOT: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square foot"),
+    abbr: Cow::Borrowed("ft²"),
+    singular: Cow::Borrowed("square foot"),
+    plural: Cow::Borrowed("square feet"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1.0 / 0.09290304,
+    exact: Some((1562500, 145161)),
+};
+
+pub static SQUARE_YARD: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square yard"),
+    abbr: Cow::Borrowed("yd²"),
+    singular: Cow::Borrowed("square yard"),
+    plural: Cow::Borrowed("square yards"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1.0 / 0.83612736,
+    exact: Some((1562500, 1306449)),
+};
+
+pub static ACRE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("acre"),
+    abbr: Cow::Borrowed("ac"),
+    singular: Cow::Borrowed("acre"),
+    plural: Cow::Borrowed("acres"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1.0 / 4046.8564224,
+    exact: Some((78125, 316160658)),
+};
+
+pub static SQUARE_MILE: AreaUnit = AreaUnit {
+    id: Cow::Borrowed("square mile"),
+    abbr: Cow::Borrowed("mi²"),
+    singular: Cow::Borrowed("square mile"),
+    plural: Cow::Borrowed("square miles"),
+    aliases: Cow::Borrowed(&[]),
+    in_square_metre: 1.0 / 2589988.110336,
+    exact: Some((15625, 40468564224)),
+};
+
+lazy_static! {
+    /// The list of US Area units are stored here
+    pub static ref US_AREA_LIST: UnitList<'static, AreaUnit> = UnitList::new(
+        "US",
+        vec!(&SQUARE_INCH, &SQUARE_FOOT, &SQUARE_YARD, &ACRE, &SQUARE_MILE,),
+    );
+}
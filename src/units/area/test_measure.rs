@@ -0,0 +1,20 @@
+use super::systems::{land::*, si::*, us::*};
+use crate::common::RoundTo;
+use crate::Measure;
+
+#[test]
+fn convert() {
+    let val = Measure::new(2.5, &HECTARE);
+    assert_eq!(val.get_val_as(&ARE).round_to(9), 250.0);
+    assert_eq!(val.get_val_as(&SQUARE_METRE).round_to(6), 25000.0);
+    assert_eq!(val.get_val_as(&SQUARE_KILOMETRE).round_to(9), 0.025);
+    // US conversions
+    let foot = Measure::new(1.0, &SQUARE_FOOT);
+    assert_eq!(foot.get_val_as(&SQUARE_INCH).round_to(5), 144.0);
+    assert_eq!(foot.get_val_as(&SQUARE_CENTIMETRE).round_to(4), 929.0304);
+    let mile = Measure::new(1.0, &SQUARE_MILE);
+    assert_eq!(mile.get_val_as(&ACRE).round_to(3), 640.0);
+    let acre = Measure::new(1.0, &ACRE);
+    assert_eq!(acre.get_val_as(&SQUARE_YARD).round_to(3), 4840.0);
+    assert_eq!(acre.get_val_as(&HECTARE).round_to(6), 0.404686);
+}
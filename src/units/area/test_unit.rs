@@ -0,0 +1,68 @@
+use super::systems::{land::*, si::*, us::*};
+use crate::common::test_util::{run_test, TestStruct};
+#[test]
+fn test_si_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec![
+                "12.5 m²",
+                "12.5m²",
+                "12.5 square metre",
+                "12.5 square metres",
+            ],
+            output: Some((12.5, &SQUARE_METRE)),
+        },
+        TestStruct {
+            inputs: vec!["3 km²", "3 square kilometres"],
+            output: Some((3.0, &SQUARE_KILOMETRE)),
+        },
+        // Test the thing(s) that should not be
+        TestStruct {
+            inputs: vec!["12 m", "12 m³", "12 ha"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&SI_AREA_LIST, val);
+    }
+}
+#[test]
+fn test_us_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["144 in²", "144 square inch", "144 square inches"],
+            output: Some((144.0, &SQUARE_INCH)),
+        },
+        TestStruct {
+            inputs: vec!["1 ft²", "1 square foot", "1 square feet"],
+            output: Some((1.0, &SQUARE_FOOT)),
+        },
+        TestStruct {
+            inputs: vec!["40 ac", "40 acre", "40 acres"],
+            output: Some((40.0, &ACRE)),
+        },
+        TestStruct {
+            inputs: vec!["40 ha", "40 m²"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&US_AREA_LIST, val);
+    }
+}
+#[test]
+fn test_land_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["2.5 ha", "2.5 hectare", "2.5 hectares"],
+            output: Some((2.5, &HECTARE)),
+        },
+        TestStruct {
+            inputs: vec!["7 a", "7 are", "7 ares"],
+            output: Some((7.0, &ARE)),
+        },
+    ];
+    for val in test_list {
+        run_test(&LAND_LIST, val);
+    }
+}
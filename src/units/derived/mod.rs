@@ -0,0 +1,45 @@
+// Multiplication and division between measures of different dimensions.
+//
+// Each operation converts both sides to coherent units (metre, second and so on),
+// and stores the result in the coherent unit of the resulting dimension.
+// Only the combinations listed here are implemented, so mixing up dimensions
+// (ex: adding an area to a length) is caught at compile time.
+use super::area::{systems::si::SQUARE_METRE, AreaUnit};
+use super::length::{systems::si::METRE, LengthUnit};
+use super::time::{systems::standard::SECOND, TimeUnit};
+use super::velocity::{systems::si::METRE_PER_SECOND, VelocityUnit};
+use super::volume::{systems::si::CUBIC_METRE, VolumeUnit};
+use crate::Measure;
+use std::ops::{Div, Mul};
+#[cfg(test)]
+mod test_measure;
+
+/// Implements an operator between two measures, resulting in a measure of another dimension.
+///
+/// Each side is given as `UnitType => coherent unit`.
+macro_rules! derived_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt,
+        $lhs:ty => $lhs_unit:expr, $rhs:ty => $rhs_unit:expr, $out:ty => $out_unit:expr) => {
+        impl<'l, 'r> $op_trait<Measure<'r, $rhs>> for Measure<'l, $lhs> {
+            type Output = Measure<'static, $out>;
+            fn $op_fn(self, other: Measure<'r, $rhs>) -> Self::Output {
+                let val = self.get_val_as(&$lhs_unit) $op other.get_val_as(&$rhs_unit);
+                Measure::new(val, &$out_unit)
+            }
+        }
+    };
+}
+
+// Length and area
+derived_op!(Mul, mul, *, LengthUnit => METRE, LengthUnit => METRE, AreaUnit => SQUARE_METRE);
+derived_op!(Div, div, /, AreaUnit => SQUARE_METRE, LengthUnit => METRE, LengthUnit => METRE);
+// Area and volume
+derived_op!(Mul, mul, *, AreaUnit => SQUARE_METRE, LengthUnit => METRE, VolumeUnit => CUBIC_METRE);
+derived_op!(Mul, mul, *, LengthUnit => METRE, AreaUnit => SQUARE_METRE, VolumeUnit => CUBIC_METRE);
+derived_op!(Div, div, /, VolumeUnit => CUBIC_METRE, LengthUnit => METRE, AreaUnit => SQUARE_METRE);
+derived_op!(Div, div, /, VolumeUnit => CUBIC_METRE, AreaUnit => SQUARE_METRE, LengthUnit => METRE);
+// Length, time and velocity
+derived_op!(Div, div, /, LengthUnit => METRE, TimeUnit => SECOND, VelocityUnit => METRE_PER_SECOND);
+derived_op!(Mul, mul, *, VelocityUnit => METRE_PER_SECOND, TimeUnit => SECOND, LengthUnit => METRE);
+derived_op!(Mul, mul, *, TimeUnit => SECOND, VelocityUnit => METRE_PER_SECOND, LengthUnit => METRE);
+derived_op!(Div, div, /, LengthUnit => METRE, VelocityUnit => METRE_PER_SECOND, TimeUnit => SECOND);
@@ -0,0 +1,50 @@
+use crate::common::RoundTo;
+use crate::units::area::systems::{si::*, us::*};
+use crate::units::length::systems::{si::*, us::*};
+use crate::units::time::systems::standard::*;
+use crate::units::velocity::systems::{si::*, us::*};
+use crate::units::volume::systems::si::*;
+use crate::Measure;
+
+#[test]
+fn length_area() {
+    let area = Measure::new(2.0, &METRE) * Measure::new(50.0, &CENTIMETRE);
+    assert_eq!(area.get_val(), 1.0);
+    assert_eq!(area.get_val_as(&SQUARE_CENTIMETRE), 10000.0);
+    // Mixing systems still gives the right area
+    let area = Measure::new(12.0, &FOOT) * Measure::new(10.0, &FOOT);
+    assert_eq!(area.get_val_as(&SQUARE_FOOT).round_to(6), 120.0);
+    // And back to a length
+    let length = area / Measure::new(12.0, &FOOT);
+    assert_eq!(length.get_val_as(&FOOT).round_to(6), 10.0);
+}
+#[test]
+fn area_volume() {
+    let area = Measure::new(2.0, &SQUARE_METRE);
+    let volume = area * Measure::new(3.0, &METRE);
+    assert_eq!(volume.get_val(), 6.0);
+    assert_eq!(volume.get_val_as(&LITRE), 6000.0);
+    let volume = Measure::new(10.0, &CENTIMETRE) * area;
+    assert_eq!(volume.get_val_as(&LITRE).round_to(9), 200.0);
+    // Split the volume back out
+    let volume = Measure::new(500.0, &LITRE);
+    let depth = volume / Measure::new(1.0, &SQUARE_METRE);
+    assert_eq!(depth.get_val_as(&CENTIMETRE).round_to(9), 50.0);
+    let area = volume / Measure::new(25.0, &CENTIMETRE);
+    assert_eq!(area.get_val().round_to(9), 2.0);
+}
+#[test]
+fn velocity() {
+    let speed = Measure::new(100.0, &KILOMETRE) / Measure::new(2.0, &HOUR);
+    assert_eq!(speed.get_val_as(&KILOMETRE_PER_HOUR).round_to(9), 50.0);
+    let speed = Measure::new(1.0, &MILE) / Measure::new(1.0, &MINUTE);
+    assert_eq!(speed.get_val_as(&MILE_PER_HOUR).round_to(3), 60.0);
+    // Distance covered in a time
+    let distance = Measure::new(60.0, &KILOMETRE_PER_HOUR) * Measure::new(90.0, &MINUTE);
+    assert_eq!(distance.get_val_as(&KILOMETRE).round_to(9), 90.0);
+    let distance = Measure::new(10.0, &SECOND) * Measure::new(3.0, &METRE_PER_SECOND);
+    assert_eq!(distance.get_val(), 30.0);
+    // Time taken to cover a distance
+    let time = Measure::new(300.0, &KILOMETRE) / Measure::new(100.0, &KILOMETRE_PER_HOUR);
+    assert_eq!(time.get_val_as(&HOUR).round_to(9), 3.0);
+}
@@ -0,0 +1,93 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use std::borrow::Cow;
+/// The different pre-made unit systems for Information
+pub mod systems;
+#[cfg(test)]
+mod test_measure;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for an Information entry.
+///
+/// The base unit for Information is the `Byte`.
+pub struct InformationUnit {
+    /// Identifies this unit among the others of its kind (ex: `kibibyte`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `KB`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many of this unit does it take to make a byte
+    in_byte: Float,
+}
+impl InformationUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `InformationUnit::new("nibble", "nibble", "nibbles", 2.0)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_byte: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_byte,
+        }
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `KB` for kilobytes)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for InformationUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_byte
+    }
+}
+impl LinearUnit for InformationUnit {}
+// Register the built-in systems
+impl RegisteredUnit for InformationUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::INFORMATION_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for InformationUnit {
+    fn eq(&self, other: &InformationUnit) -> bool {
+        self.is_identical(other)
+    }
+}
@@ -0,0 +1,56 @@
+use super::super::InformationUnit;
+use super::decimal::BYTE;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static KIBIBYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("kibibyte"),
+    abbr: Cow::Borrowed("KiB"),
+    singular: Cow::Borrowed("kibibyte"),
+    plural: Cow::Borrowed("kibibytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 1.0 / 1024.0,
+};
+
+pub static MEBIBYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("mebibyte"),
+    abbr: Cow::Borrowed("MiB"),
+    singular: Cow::Borrowed("mebibyte"),
+    plural: Cow::Borrowed("mebibytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 1.0 / 1_048_576.0,
+};
+
+pub static GIBIBYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("gibibyte"),
+    abbr: Cow::Borrowed("GiB"),
+    singular: Cow::Borrowed("gibibyte"),
+    plural: Cow::Borrowed("gibibytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 1.0 / 1_073_741_824.0,
+};
+
+pub static TEBIBYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("tebibyte"),
+    abbr: Cow::Borrowed("TiB"),
+    singular: Cow::Borrowed("tebibyte"),
+    plural: Cow::Borrowed("tebibytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 1.0 / 1_099_511_627_776.0,
+};
+
+pub static PEBIBYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("pebibyte"),
+    abbr: Cow::Borrowed("PiB"),
+    singular: Cow::Borrowed("pebibyte"),
+    plural: Cow::Borrowed("pebibytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 1.0 / 1_125_899_906_842_624.0,
+};
+
+lazy_static! {
+    /// The list of IEC binary (powers of 1024) byte units are stored here
+    pub static ref BINARY_LIST: UnitList<'static, InformationUnit> = UnitList::new(
+        "Binary",
+        vec!(&BYTE, &KIBIBYTE, &MEBIBYTE, &GIBIBYTE, &TEBIBYTE, &PEBIBYTE,),
+    );
+}
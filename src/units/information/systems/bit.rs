@@ -0,0 +1,55 @@
+use super::super::InformationUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static BIT: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("bit"),
+    abbr: Cow::Borrowed("bit"),
+    singular: Cow::Borrowed("bit"),
+    plural: Cow::Borrowed("bits"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 8.0,
+};
+
+pub static KILOBIT: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("kilobit"),
+    abbr: Cow::Borrowed("kbit"),
+    singular: Cow::Borrowed("kilobit"),
+    plural: Cow::Borrowed("kilobits"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.008,
+};
+
+pub static MEGABIT: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("megabit"),
+    abbr: Cow::Borrowed("Mbit"),
+    singular: Cow::Borrowed("megabit"),
+    plural: Cow::Borrowed("megabits"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000008,
+};
+
+pub static GIGABIT: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("gigabit"),
+    abbr: Cow::Borrowed("Gbit"),
+    singular: Cow::Borrowed("gigabit"),
+    plural: Cow::Borrowed("gigabits"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000000008,
+};
+
+pub static TERABIT: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("terabit"),
+    abbr: Cow::Borrowed("Tbit"),
+    singular: Cow::Borrowed("terabit"),
+    plural: Cow::Borrowed("terabits"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000000000008,
+};
+
+lazy_static! {
+    /// The list of bit units (ex: for bandwidth) are stored here
+    pub static ref BIT_LIST: UnitList<'static, InformationUnit> = UnitList::new(
+        "Bit",
+        vec!(&BIT, &KILOBIT, &MEGABIT, &GIGABIT, &TERABIT,),
+    );
+}
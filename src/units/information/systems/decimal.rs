@@ -0,0 +1,64 @@
+use super::super::InformationUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static BYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("byte"),
+    abbr: Cow::Borrowed("B"),
+    singular: Cow::Borrowed("byte"),
+    plural: Cow::Borrowed("bytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 1.0,
+};
+
+pub static KILOBYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("kilobyte"),
+    abbr: Cow::Borrowed("kB"),
+    singular: Cow::Borrowed("kilobyte"),
+    plural: Cow::Borrowed("kilobytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.001,
+};
+
+pub static MEGABYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("megabyte"),
+    abbr: Cow::Borrowed("MB"),
+    singular: Cow::Borrowed("megabyte"),
+    plural: Cow::Borrowed("megabytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000001,
+};
+
+pub static GIGABYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("gigabyte"),
+    abbr: Cow::Borrowed("GB"),
+    singular: Cow::Borrowed("gigabyte"),
+    plural: Cow::Borrowed("gigabytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000000001,
+};
+
+pub static TERABYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("terabyte"),
+    abbr: Cow::Borrowed("TB"),
+    singular: Cow::Borrowed("terabyte"),
+    plural: Cow::Borrowed("terabytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000000000001,
+};
+
+pub static PETABYTE: InformationUnit = InformationUnit {
+    id: Cow::Borrowed("petabyte"),
+    abbr: Cow::Borrowed("PB"),
+    singular: Cow::Borrowed("petabyte"),
+    plural: Cow::Borrowed("petabytes"),
+    aliases: Cow::Borrowed(&[]),
+    in_byte: 0.000000000000001,
+};
+
+lazy_static! {
+    /// The list of decimal (powers of 1000) byte units are stored here
+    pub static ref DECIMAL_LIST: UnitList<'static, InformationUnit> = UnitList::new(
+        "Decimal",
+        vec!(&BYTE, &KILOBYTE, &MEGABYTE, &GIGABYTE, &TERABYTE, &PETABYTE,),
+    );
+}
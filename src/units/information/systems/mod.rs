@@ -0,0 +1,14 @@
+use super::InformationUnit;
+use crate::registry::UnitRegistry;
+/// Module with IEC binary byte units (Kibibyte and such)
+pub mod binary;
+/// Module with bit units (Bit, Megabit and such)
+pub mod bit;
+/// Module with decimal byte units (Byte, Kilobyte and such)
+pub mod decimal;
+
+lazy_static! {
+    /// Every built-in Information system, for looking units up by name
+    pub static ref INFORMATION_REGISTRY: UnitRegistry<InformationUnit> =
+        UnitRegistry::with_systems(&[&decimal::DECIMAL_LIST, &binary::BINARY_LIST, &bit::BIT_LIST]);
+}
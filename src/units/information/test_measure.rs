@@ -0,0 +1,47 @@
+use super::systems::{binary::*, bit::*, decimal::*};
+use crate::common::RoundTo;
+use crate::{BestUnitRule, Measure};
+
+#[test]
+fn convert() {
+    let val = Measure::new(1.0, &GIBIBYTE);
+    assert_eq!(val.get_val_as(&BYTE), 1_073_741_824.0);
+    assert_eq!(val.get_val_as(&MEBIBYTE).round_to(9), 1024.0);
+    assert_eq!(val.get_val_as(&GIGABYTE).round_to(9), 1.073741824);
+    let val = Measure::new(2.0, &MEGABYTE);
+    assert_eq!(val.get_val_as(&KILOBYTE).round_to(9), 2000.0);
+    assert_eq!(val.get_val_as(&MEGABIT).round_to(9), 16.0);
+    assert_eq!(Measure::new(1.0, &BYTE).get_val_as(&BIT), 8.0);
+}
+#[test]
+fn from_literal() {
+    let val = Measure::from_literal(&BINARY_LIST, "1.5 GiB").unwrap();
+    assert_eq!(val.get_val_as(&MEBIBYTE).round_to(9), 1536.0);
+    assert_eq!(val.display(1), "1.5 gibibytes");
+    assert!(Measure::from_literal(&BINARY_LIST, "1.5 GB").is_none());
+}
+#[test]
+fn best_unit() {
+    let rule = BestUnitRule::new();
+    let val = Measure::new(1_500_000.0, &BYTE);
+    assert_eq!(
+        val.to_best_unit(&DECIMAL_LIST, &rule).display_abbr(1),
+        "1.5MB"
+    );
+    assert_eq!(
+        val.to_best_unit(&BINARY_LIST, &rule).display_abbr(2),
+        "1.43MiB"
+    );
+    // The binary list counts in steps of 1024
+    let rule = BestUnitRule::new().with_range(1.0, 1024.0);
+    let val = Measure::new(1000.0, &KIBIBYTE);
+    assert_eq!(
+        val.to_best_unit(&BINARY_LIST, &rule).display_abbr(0),
+        "1000KiB"
+    );
+    let val = Measure::new(250_000.0, &KILOBIT);
+    assert_eq!(
+        val.to_best_unit(&BIT_LIST, &rule).display_abbr(0),
+        "250Mbit"
+    );
+}
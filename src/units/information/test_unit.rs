@@ -0,0 +1,71 @@
+use super::systems::{binary::*, bit::*, decimal::*};
+use crate::common::test_util::{run_test, TestStruct};
+#[test]
+fn test_decimal_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["512 B", "512 byte", "512 bytes", "512B"],
+            output: Some((512.0, &BYTE)),
+        },
+        TestStruct {
+            inputs: vec!["1.5 GB", "1.5 gigabyte", "1.5 gigabytes"],
+            output: Some((1.5, &GIGABYTE)),
+        },
+        TestStruct {
+            inputs: vec!["20 kB", "20 kilobytes"],
+            output: Some((20.0, &KILOBYTE)),
+        },
+        // Test the thing(s) that should not be
+        TestStruct {
+            inputs: vec!["1.5 GiB", "12 bit", "20 KB"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&DECIMAL_LIST, val);
+    }
+}
+#[test]
+fn test_binary_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["1.5 GiB", "1.5 gibibyte", "1.5 gibibytes"],
+            output: Some((1.5, &GIBIBYTE)),
+        },
+        TestStruct {
+            inputs: vec!["640 KiB", "640 kibibytes"],
+            output: Some((640.0, &KIBIBYTE)),
+        },
+        TestStruct {
+            inputs: vec!["3 B", "3 bytes"],
+            output: Some((3.0, &BYTE)),
+        },
+        TestStruct {
+            inputs: vec!["1.5 GB", "12 Mbit"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&BINARY_LIST, val);
+    }
+}
+#[test]
+fn test_bit_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["100 Mbit", "100 megabit", "100 megabits"],
+            output: Some((100.0, &MEGABIT)),
+        },
+        TestStruct {
+            inputs: vec!["8 bit", "8 bits"],
+            output: Some((8.0, &BIT)),
+        },
+        TestStruct {
+            inputs: vec!["100 MB", "100 MiB"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&BIT_LIST, val);
+    }
+}
@@ -1,7 +1,14 @@
+use crate::common::ParseError;
+use crate::registry::{RegisteredUnit, UnitRegistry};
 use crate::unit_creation::*;
+use crate::Measure;
+use std::borrow::Cow;
+use std::str::FromStr;
 /// The different pre-made unit systems for Length
 pub mod systems;
 #[cfg(test)]
+mod test_locale;
+#[cfg(test)]
 mod test_measure;
 #[cfg(test)]
 mod test_unit;
@@ -9,34 +16,147 @@ mod test_unit;
 ///
 /// The base unit for Length is `Metre`.
 pub struct LengthUnit {
+    /// Identifies this unit among the others of its kind (ex: `metre`); `==` compares only this
+    id: Cow<'static, str>,
     /// The abbreviation for this unit
-    abbr: &'static str,
+    abbr: Cow<'static, str>,
     /// Full name of this unit (singular)
-    singular: &'static str,
+    singular: Cow<'static, str>,
     /// Plural name of this unit
-    plural: &'static str,
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `ft.`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// Symbol written straight after the number (ex: `'` for feet), if there is one
+    symbol: Option<Cow<'static, str>>,
     /// How many of this unit does it take to make a metre
     in_metre: Float,
+    /// The exact `in_metre` as (numerator, denominator), when `in_metre` is rounded
+    exact: Option<(i64, i64)>,
+}
+impl LengthUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// The names may be owned, for units defined at runtime (ex: loaded from a config):
+    /// `LengthUnit::new("ftm", "fathom", "fathoms", 1.0 / 1.8288)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_metre: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            symbol: None,
+            in_metre,
+            exact: None,
+        }
+    }
+    /// Sets the exact `in_metre` as a ratio (ex: `5000 / 127` for the inch), for exact conversions
+    pub fn with_exact(mut self, numerator: i64, denominator: i64) -> Self {
+        self.exact = Some((numerator, denominator));
+        self
+    }
+    /// Sets the symbol written straight after the number (ex: `'` for feet)
+    pub fn with_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `mtr` for metres)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
 }
 // Implement the UnitTrait
 impl UnitTrait for LengthUnit {
-    fn get_abbr(&self) -> &'static str {
-        self.abbr
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
     }
-    fn get_singular(&self) -> &'static str {
-        self.singular
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
     }
-    fn get_plural(&self) -> &'static str {
-        self.plural
+    fn get_symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
     }
     fn in_base(&self) -> Float {
         self.in_metre
     }
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        self.exact
+    }
+}
+impl LinearUnit for LengthUnit {}
+// Derive the prefixed units (ex: `km` from `m`)
+impl PrefixableUnit for LengthUnit {
+    fn with_prefix(&self, prefix: &Prefix) -> Self {
+        Self::new(
+            prefix.prefix_abbr(&self.abbr),
+            prefix.prefix_name(&self.singular),
+            prefix.prefix_name(&self.plural),
+            prefix.prefix_in_base(self.in_metre),
+        )
+        .with_id(prefix.prefix_name(&self.id))
+    }
+}
+// Register the built-in systems
+impl RegisteredUnit for LengthUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::LENGTH_REGISTRY
+    }
 }
-// Implement the equality operator
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
 impl std::cmp::PartialEq for LengthUnit {
     fn eq(&self, other: &LengthUnit) -> bool {
-        // TODO: What about spellings?
-        self.in_metre == other.in_metre
+        self.is_identical(other)
+    }
+}
+impl LengthUnit {
+    /// Finds any built-in unit (ex: `ft`), or any SI prefix of the metre (ex: `µm`).
+    ///
+    /// These are the units `str::parse` finds for a Length.
+    pub fn find_built_in(unit_str: &str) -> Result<&'static LengthUnit, ParseError> {
+        match systems::ALL_LENGTH_LIST.try_find_in_list(unit_str) {
+            Err(ParseError::UnknownUnit(unit)) => systems::si::SI_PREFIXED_LENGTH_LIST
+                .find(unit_str)
+                .ok_or(ParseError::UnknownUnit(unit)),
+            result => result,
+        }
+    }
+}
+// Parse a Length from any of the built-in units (ex: `"3 ft".parse()`),
+// or any SI prefix of the metre (ex: `"3 µm".parse()`)
+impl FromStr for Measure<'static, LengthUnit> {
+    type Err = ParseError;
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        match Measure::try_from_literal(&systems::ALL_LENGTH_LIST, val) {
+            Err(ParseError::UnknownUnit(unit)) => {
+                match systems::si::SI_PREFIXED_LENGTH_LIST.try_parse_str(val) {
+                    Ok((val, unit)) => Ok(Measure::new(val, unit)),
+                    Err(_) => Err(ParseError::UnknownUnit(unit)),
+                }
+            }
+            result => result,
+        }
     }
 }
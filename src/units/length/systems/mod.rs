@@ -1,6 +1,28 @@
+use super::LengthUnit;
+use crate::registry::UnitRegistry;
+use crate::unit_creation::UnitList;
 /// Module with the United States customary units (Inch and such)
 pub mod us;
 /// Module with SI units (Metre and such)
 pub mod si;
 /// Module with American spellings of SI units (Meter and such)
 pub mod si_us;
+
+lazy_static! {
+    /// Every built-in Length unit (SI, American SI spellings and US).
+    ///
+    /// This is the list used when parsing a Length with `str::parse`.
+    pub static ref ALL_LENGTH_LIST: UnitList<'static, LengthUnit> = {
+        let mut list = UnitList::new("All", si::SI_LIST.get_list().clone());
+        list.merge_other(si_us::SI_US_LIST.get_list());
+        list.merge_other(us::US_LENGTH_LIST.get_list());
+        list
+    };
+    /// Every built-in Length system, for looking units up by name
+    pub static ref LENGTH_REGISTRY: UnitRegistry<LengthUnit> = UnitRegistry::with_systems(&[
+        &si::SI_LIST,
+        &si_us::SI_US_LIST,
+        &us::US_LENGTH_LIST,
+        &si::SI_PREFIXED_LENGTH_LIST.list(),
+    ]);
+}
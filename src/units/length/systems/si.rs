@@ -1,38 +1,60 @@
 use super::super::LengthUnit;
+use crate::prefix::PrefixedUnits;
 use crate::unit_creation::UnitList;
+use std::borrow::Cow;
 pub static MILLIMETRE: LengthUnit = LengthUnit {
-    abbr: "mm",
-    singular: "millimetre",
-    plural: "millimetres",
+    id: Cow::Borrowed("millimetre"),
+    abbr: Cow::Borrowed("mm"),
+    singular: Cow::Borrowed("millimetre"),
+    plural: Cow::Borrowed("millimetres"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 1000.0,
+    exact: None,
 };
 
 pub static CENTIMETRE: LengthUnit = LengthUnit {
-    abbr: "cm",
-    singular: "centimetre",
-    plural: "centimetres",
+    id: Cow::Borrowed("centimetre"),
+    abbr: Cow::Borrowed("cm"),
+    singular: Cow::Borrowed("centimetre"),
+    plural: Cow::Borrowed("centimetres"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 100.0,
+    exact: None,
 };
 
 pub static DECIMETRE: LengthUnit = LengthUnit {
-    abbr: "dm",
-    singular: "decimetre",
-    plural: "decimetres",
+    id: Cow::Borrowed("decimetre"),
+    abbr: Cow::Borrowed("dm"),
+    singular: Cow::Borrowed("decimetre"),
+    plural: Cow::Borrowed("decimetres"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 10.0,
+    exact: None,
 };
 
 pub static METRE: LengthUnit = LengthUnit {
-    abbr: "m",
-    singular: "metre",
-    plural: "metres",
+    id: Cow::Borrowed("metre"),
+    abbr: Cow::Borrowed("m"),
+    singular: Cow::Borrowed("metre"),
+    plural: Cow::Borrowed("metres"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("mtr"), Cow::Borrowed("mtrs")]),
+    symbol: None,
     in_metre: 1.0,
+    exact: None,
 };
 
 pub static KILOMETRE: LengthUnit = LengthUnit {
-    abbr: "km",
-    singular: "kilometre",
-    plural: "kilometres",
+    id: Cow::Borrowed("kilometre"),
+    abbr: Cow::Borrowed("km"),
+    singular: Cow::Borrowed("kilometre"),
+    plural: Cow::Borrowed("kilometres"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 0.001,
+    exact: None,
 };
 
 lazy_static! {
@@ -40,4 +62,6 @@ lazy_static! {
         "SI",
         vec!(&MILLIMETRE, &CENTIMETRE, &DECIMETRE, &METRE, &KILOMETRE,),
     );
+    /// Every SI prefix of the Metre (ex: `µm`), found without listing each one
+    pub static ref SI_PREFIXED_LENGTH_LIST: PrefixedUnits<LengthUnit> = PrefixedUnits::new(&METRE);
 }
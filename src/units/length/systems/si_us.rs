@@ -1,38 +1,59 @@
 use super::super::LengthUnit;
 use crate::unit_creation::UnitList;
+use std::borrow::Cow;
 pub static MILLIMETER: LengthUnit = LengthUnit {
-    abbr: "mm",
-    singular: "millimeter",
-    plural: "millimeters",
+    id: Cow::Borrowed("millimeter"),
+    abbr: Cow::Borrowed("mm"),
+    singular: Cow::Borrowed("millimeter"),
+    plural: Cow::Borrowed("millimeters"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 1000.0,
+    exact: None,
 };
 
 pub static CENTIMETER: LengthUnit = LengthUnit {
-    abbr: "cm",
-    singular: "centimeter",
-    plural: "centimeters",
+    id: Cow::Borrowed("centimeter"),
+    abbr: Cow::Borrowed("cm"),
+    singular: Cow::Borrowed("centimeter"),
+    plural: Cow::Borrowed("centimeters"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 100.0,
+    exact: None,
 };
 
 pub static DECIMETER: LengthUnit = LengthUnit {
-    abbr: "dm",
-    singular: "decimeter",
-    plural: "decimeters",
+    id: Cow::Borrowed("decimeter"),
+    abbr: Cow::Borrowed("dm"),
+    singular: Cow::Borrowed("decimeter"),
+    plural: Cow::Borrowed("decimeters"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 10.0,
+    exact: None,
 };
 
 pub static METER: LengthUnit = LengthUnit {
-    abbr: "m",
-    singular: "meter",
-    plural: "meters",
+    id: Cow::Borrowed("meter"),
+    abbr: Cow::Borrowed("m"),
+    singular: Cow::Borrowed("meter"),
+    plural: Cow::Borrowed("meters"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("mtr"), Cow::Borrowed("mtrs")]),
+    symbol: None,
     in_metre: 1.0,
+    exact: None,
 };
 
 pub static KILOMETER: LengthUnit = LengthUnit {
-    abbr: "km",
-    singular: "kilometer",
-    plural: "kilometers",
+    id: Cow::Borrowed("kilometer"),
+    abbr: Cow::Borrowed("km"),
+    singular: Cow::Borrowed("kilometer"),
+    plural: Cow::Borrowed("kilometers"),
+    aliases: Cow::Borrowed(&[]),
+    symbol: None,
     in_metre: 0.001,
+    exact: None,
 };
 
 lazy_static! {
@@ -1,31 +1,48 @@
 use super::super::LengthUnit;
 use crate::unit_creation::UnitList;
+use std::borrow::Cow;
 pub static INCH: LengthUnit = LengthUnit {
-    abbr: "in",
-    singular: "inch",
-    plural: "inches",
-    in_metre: 39.37007874,
+    id: Cow::Borrowed("inch"),
+    abbr: Cow::Borrowed("in"),
+    singular: Cow::Borrowed("inch"),
+    plural: Cow::Borrowed("inches"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("in.")]),
+    symbol: Some(Cow::Borrowed("\"")),
+    in_metre: 1.0 / 0.0254,
+    exact: Some((5000, 127)),
 };
 
 pub static FOOT: LengthUnit = LengthUnit {
-    abbr: "ft",
-    singular: "foot",
-    plural: "feet",
-    in_metre: 3.280839895,
+    id: Cow::Borrowed("foot"),
+    abbr: Cow::Borrowed("ft"),
+    singular: Cow::Borrowed("foot"),
+    plural: Cow::Borrowed("feet"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("ft.")]),
+    symbol: Some(Cow::Borrowed("'")),
+    in_metre: 1.0 / 0.3048,
+    exact: Some((1250, 381)),
 };
 
 pub static YARD: LengthUnit = LengthUnit {
-    abbr: "yd",
-    singular: "yard",
-    plural: "yards",
-    in_metre: 1.093613298,
+    id: Cow::Borrowed("yard"),
+    abbr: Cow::Borrowed("yd"),
+    singular: Cow::Borrowed("yard"),
+    plural: Cow::Borrowed("yards"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("yd."), Cow::Borrowed("yds")]),
+    symbol: None,
+    in_metre: 1.0 / 0.9144,
+    exact: Some((1250, 1143)),
 };
 
 pub static MILE: LengthUnit = LengthUnit {
-    abbr: "mi",
-    singular: "mile",
-    plural: "miles",
-    in_metre: 0.000621371,
+    id: Cow::Borrowed("mile"),
+    abbr: Cow::Borrowed("mi"),
+    singular: Cow::Borrowed("mile"),
+    plural: Cow::Borrowed("miles"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("mi.")]),
+    symbol: None,
+    in_metre: 1.0 / 1609.344,
+    exact: Some((125, 201168)),
 };
 
 lazy_static! {
@@ -0,0 +1,182 @@
+use super::systems::si::*;
+use super::systems::us::*;
+use crate::base_types::UnitTrait;
+use crate::common::*;
+use crate::locale::*;
+use crate::Measure;
+/// Polish with the names of the metre
+fn polish() -> Locale {
+    Locale::polish()
+        .with_unit_name(&METRE, PluralCategory::One, "metr")
+        .with_unit_name(&METRE, PluralCategory::Few, "metry")
+        .with_unit_name(&METRE, PluralCategory::Many, "metrów")
+        .with_unit_name(&METRE, PluralCategory::Other, "metra")
+}
+#[test]
+fn test_plural_rules() {
+    let categories = |rule: PluralRule, vals: &[Float]| -> Vec<PluralCategory> {
+        vals.iter().map(|val| rule.category(*val, 0)).collect()
+    };
+    use PluralCategory::*;
+    assert_eq!(
+        categories(PluralRule::English, &[0.0, 1.0, 1.5, 2.0]),
+        vec![Other, One, Other, Other]
+    );
+    assert_eq!(
+        categories(PluralRule::French, &[0.0, 1.5, 2.0]),
+        vec![One, One, Other]
+    );
+    assert_eq!(
+        categories(PluralRule::Polish, &[1.0, 2.0, 5.0, 12.0, 22.0, 21.0, 1.5]),
+        vec![One, Few, Many, Many, Few, Many, Other]
+    );
+    assert_eq!(
+        categories(
+            PluralRule::Russian,
+            &[1.0, 21.0, 11.0, 3.0, 13.0, 25.0, 2.5]
+        ),
+        vec![One, One, Many, Few, Many, Many, Other]
+    );
+    assert_eq!(categories(PluralRule::Invariant, &[1.0]), vec![Other]);
+    // Decimals shown make a fraction, even when they are `0` (but not in English)
+    assert_eq!(PluralRule::English.category(1.0, 1), One);
+    assert_eq!(PluralRule::Polish.category(2.0, 2), Other);
+    assert_eq!(PluralRule::Russian.category(21.0, 1), Other);
+    assert_eq!(PluralRule::French.category(1.0, 1), One);
+}
+#[test]
+fn test_display() {
+    let locale = polish();
+    assert_eq!(Measure::new(1.0, &METRE).display_in(0, &locale), "1 metr");
+    assert_eq!(Measure::new(2.0, &METRE).display_in(0, &locale), "2 metry");
+    assert_eq!(Measure::new(5.0, &METRE).display_in(0, &locale), "5 metrów");
+    assert_eq!(
+        Measure::new(1.5, &METRE).display_in(1, &locale),
+        "1,5 metra"
+    );
+    assert_eq!(
+        Measure::new(1.0, &METRE).display_in(1, &locale),
+        "1,0 metra"
+    );
+    assert_eq!(
+        Measure::new(1.0, &METRE).display_in(1, &Locale::english()),
+        "1.0 metre"
+    );
+    assert_eq!(
+        Measure::new(1.0, &METRE).display_in(1, &Locale::french()),
+        "1,0 metre"
+    );
+    assert_eq!(
+        Measure::new(-1234.5, &METRE).display_in(1, &locale),
+        "-1\u{A0}234,5 metra"
+    );
+    // Units without a translation keep their own names
+    assert_eq!(Measure::new(2.0, &FOOT).display_in(0, &locale), "2 feet");
+    // Including units of other dimensions with the same abbreviation
+    use crate::units::area::systems::land::ARE;
+    use crate::units::time::systems::astronomical::JULIAN_YEAR;
+    let locale = polish().with_unit_name(&ARE, PluralCategory::Other, "arów");
+    assert_eq!(Measure::new(5.0, &ARE).display_in(0, &locale), "5 arów");
+    assert_eq!(
+        Measure::new(5.0, &JULIAN_YEAR).display_in(0, &locale),
+        "5 Julian years"
+    );
+    let german = Locale::german();
+    assert_eq!(
+        Measure::new(1234.5, &METRE).display_abbr_in(2, &german),
+        "1.234,50m"
+    );
+    assert_eq!(
+        Measure::new(1234567.0, &METRE).display_in(0, &Locale::english()),
+        "1,234,567 metres"
+    );
+    // The formatter takes the same flags as Display
+    let length = Measure::new(1234.5, &METRE);
+    assert_eq!(format!("{}", length.localized(&german)), "1.234,5 m");
+    assert_eq!(format!("{:.2}", length.localized(&german)), "1.234,50 m");
+    assert_eq!(
+        format!("{:#.0}", length.localized(&locale)),
+        "1\u{A0}235 metrów"
+    );
+    let one = Measure::new(1.0, &METRE);
+    assert_eq!(format!("{:#}", one.localized(&locale)), "1 metr");
+    assert_eq!(format!("{:#.1}", one.localized(&locale)), "1,0 metra");
+    // As well as the width, fill and alignment
+    assert_eq!(format!("{:>12}", length.localized(&german)), "   1.234,5 m");
+    assert_eq!(format!("{:<8.1}|", one.localized(&german)), "1,0 m   |");
+    assert_eq!(format!("{:*^11.0}", one.localized(&german)), "****1 m****");
+}
+#[test]
+fn test_parse() {
+    let locale = polish();
+    let res = locale.parse_str(&SI_LIST, "1\u{A0}234,5 m").unwrap();
+    assert_eq!(res.0, 1234.5);
+    assert_eq!(res.1.get_abbr(), "m");
+    let res = locale.parse_str(&SI_LIST, "1 234,5 metrów").unwrap();
+    assert_eq!(res.0, 1234.5);
+    assert_eq!(res.1.get_abbr(), "m");
+    let res = locale.parse_str(&SI_LIST, "5 Metry").unwrap();
+    assert_eq!(res.0, 5.0);
+    // The names of the units still work
+    let res = locale.parse_str(&SI_LIST, "2,5 kilometres").unwrap();
+    assert_eq!(res.0, 2.5);
+    assert_eq!(res.1.get_abbr(), "km");
+    let res = Locale::german()
+        .parse_str(&US_LENGTH_LIST, "1.234,5 ft")
+        .unwrap();
+    assert_eq!(res.0, 1234.5);
+    let res = Locale::german().parse_str(&SI_LIST, "1,5e3 m").unwrap();
+    assert_eq!(res.0, 1500.0);
+    let res = Locale::english()
+        .parse_str(&US_LENGTH_LIST, "3 1/2 in")
+        .unwrap();
+    assert_eq!(res.0, 3.5);
+    let length = Locale::german()
+        .try_parse_measure(&SI_LIST, "12,5 km")
+        .unwrap();
+    assert_eq!(length.get_val(), 12.5);
+    // The errors are the same as UnitList::try_parse_str
+    assert_eq!(
+        Locale::english().try_parse_str(&SI_LIST, "1,5 m").err(),
+        Some(ParseError::BadNumber(String::from("1,5")))
+    );
+    assert_eq!(
+        locale.try_parse_str(&SI_LIST, "5 stóp").err(),
+        Some(ParseError::UnknownUnit(String::from("stóp")))
+    );
+    assert_eq!(
+        locale.try_parse_str(&SI_LIST, "metrów").err(),
+        Some(ParseError::BadNumber(String::from("metrów")))
+    );
+    assert_eq!(
+        locale.try_parse_str(&SI_LIST, " ").err(),
+        Some(ParseError::Empty)
+    );
+    // Translated names only find the unit they were given for
+    assert_eq!(
+        locale.try_parse_str(&US_LENGTH_LIST, "5 metrów").err(),
+        Some(ParseError::UnknownUnit(String::from("metrów")))
+    );
+}
+#[test]
+fn test_english_matches_display() {
+    // English picks the same names as plain display, whatever the rounding
+    let english = Locale::new("en");
+    for val in [0.999, 1.0, 1.04, 1.5, 2.0, -1.0] {
+        let length = Measure::new(val, &KILOMETRE);
+        for decimals in 0..3 {
+            assert_eq!(
+                length.display_in(decimals, &english),
+                length.display(decimals)
+            );
+            assert_eq!(
+                format!("{:#.*}", decimals, length.localized(&english)),
+                format!("{:#.*}", decimals, length)
+            );
+        }
+        assert_eq!(
+            format!("{:#}", length.localized(&english)),
+            format!("{:#}", length)
+        );
+    }
+}
@@ -1,5 +1,7 @@
 // Move on to the tests
 use super::systems::si::*;
+use crate::base_types::UnitTrait;
+use crate::common::RoundTo;
 use crate::Measure;
 
 #[test]
@@ -90,3 +92,367 @@ fn convert() {
 // fn borrow(val: &mut Measure, val2: &Measure) {
 //     val.add_other(val2);
 // }
+#[test]
+fn scalar() {
+    let val = Measure::new(1.5, &METRE);
+    // Multiply and divide keep the unit
+    let double = val * 2.0;
+    assert_eq!(double.get_val(), 3.0);
+    assert_eq!(double.get_val_as(&CENTIMETRE), 300.0);
+    let double = 2.0 * val;
+    assert_eq!(double.get_val(), 3.0);
+    let half = val / 2.0;
+    assert_eq!(half.get_val(), 0.75);
+    let negative = -val;
+    assert_eq!(negative.get_val(), -1.5);
+    assert_eq!(negative.get_val_as(&MILLIMETRE), -1500.0);
+    // And in place
+    let mut val = Measure::new(250.0, &CENTIMETRE);
+    val *= 4.0;
+    assert_eq!(val.get_val(), 1000.0);
+    val /= 8.0;
+    assert_eq!(val.get_val(), 125.0);
+}
+#[test]
+fn ratio() {
+    let long = Measure::new(1.0, &KILOMETRE);
+    let short = Measure::new(250.0, &METRE);
+    assert_eq!(long / short, 4.0);
+    assert_eq!(short / long, 0.25);
+    assert_eq!(long.ratio(&short), 4.0);
+    assert_eq!(
+        Measure::new(5.0, &MILLIMETRE).ratio(&Measure::new(1.0, &CENTIMETRE)),
+        0.5
+    );
+}
+#[test]
+fn runtime_units() {
+    use super::LengthUnit;
+    use crate::base_types::{UnitList, UnitTrait};
+    // Units built from owned strings, as if they were loaded from a config
+    let names = vec![("fathom", 0.546806649), ("league", 0.000207123741)];
+    let units: Vec<LengthUnit> = names
+        .into_iter()
+        .map(|(name, in_metre)| {
+            LengthUnit::new(
+                name[..2].to_string(),
+                name.to_string(),
+                format!("{}s", name),
+                in_metre,
+            )
+        })
+        .collect();
+    let list = UnitList::new(String::from("Nautical"), units.iter().collect());
+    assert_eq!(list.get_title(), "Nautical");
+    let mut val = Measure::from_literal(&list, "10 fathoms").unwrap();
+    assert_eq!(val.get_unit().get_singular(), "fathom");
+    assert_eq!(val.get_val_as(&METRE).round_to(4), 18.288);
+    // They mix freely with the built-in units
+    val += Measure::new(1.8288, &METRE);
+    assert_eq!(val.get_val().round_to(6), 11.0);
+    val.convert_to(&units[1]);
+    assert_eq!(val.display_abbr(4), "0.0042le");
+    let total = Measure::new(1.0, &KILOMETRE) + Measure::new(1.0, &units[1]);
+    assert_eq!(total.get_val().round_to(3), 5.828);
+}
+#[test]
+fn test_format() {
+    let val = Measure::new(1.5, &METRE);
+    assert_eq!(format!("{}", val), "1.5 m");
+    assert_eq!(format!("{:.2}", val), "1.50 m");
+    assert_eq!(format!("{:#}", val), "1.5 metres");
+    assert_eq!(format!("{:#.0}", val), "2 metres");
+    assert_eq!(val.to_string(), "1.5 m");
+    // The singular is chosen after rounding
+    let val = Measure::new(0.999, &KILOMETRE);
+    assert_eq!(format!("{:#}", val), "0.999 kilometres");
+    assert_eq!(format!("{:#.1}", val), "1.0 kilometre");
+    assert_eq!(format!("{:.4}", val), "0.9990 km");
+    // The width applies to the whole text, not just the value
+    let val = Measure::new(1.5, &METRE);
+    assert_eq!(format!("{:>8}", val), "   1.5 m");
+    assert_eq!(format!("{:<8.2}|", val), "1.50 m  |");
+    assert_eq!(format!("{:*^8}", val), "*1.5 m**");
+    assert_eq!(format!("{:#12.1}|", val), "1.5 metres  |");
+}
+#[test]
+fn test_from_str() {
+    use super::systems::{si_us::METER, us::FOOT};
+    use crate::common::ParseError;
+    use crate::units::length::LengthUnit;
+    let val: Measure<LengthUnit> = "3 ft".parse().unwrap();
+    assert_eq!(val.get_val(), 3.0);
+    assert_eq!(val.get_unit().get_abbr(), FOOT.get_abbr());
+    let val: Measure<LengthUnit> = "12.5 meters".parse().unwrap();
+    assert_eq!(val.get_unit().get_singular(), METER.get_singular());
+    let val: Measure<LengthUnit> = "12.5 metres".parse().unwrap();
+    assert_eq!(val.get_val_as(&CENTIMETRE), 1250.0);
+    // Round trip through Display
+    let val: Measure<LengthUnit> = Measure::new(2.25, &KILOMETRE).to_string().parse().unwrap();
+    assert_eq!(val.get_val_as(&METRE), 2250.0);
+    let err = "3 furlongs".parse::<Measure<LengthUnit>>().err().unwrap();
+    assert_eq!(err, ParseError::UnknownUnit(String::from("furlongs")));
+}
+#[test]
+fn test_compound_literal() {
+    use super::systems::us::{INCH, US_LENGTH_LIST};
+    let val = Measure::from_literal(&US_LENGTH_LIST, "5'11\"").unwrap();
+    assert_eq!(val.get_val_as(&INCH).round_to(9), 71.0);
+    assert_eq!(val.get_val_as(&CENTIMETRE).round_to(4), 180.34);
+    let val = Measure::from_literal(&SI_LIST, "2 km 350 m").unwrap();
+    assert_eq!(val.get_val(), 2350.0);
+}
+#[test]
+fn test_compound_display() {
+    use super::systems::us::*;
+    let height = Measure::new(1.8, &METRE);
+    assert_eq!(
+        height.display_compound_abbr(&[&FOOT, &INCH], 1),
+        "5 ft 10.9 in"
+    );
+    assert_eq!(
+        height.display_compound(&[&FOOT, &INCH], 0),
+        "5 feet 11 inches"
+    );
+    // The order of the units does not matter
+    assert_eq!(
+        height.display_compound_abbr(&[&INCH, &FOOT], 1),
+        "5 ft 10.9 in"
+    );
+    let val = Measure::new(2.35, &KILOMETRE);
+    assert_eq!(
+        val.display_compound_abbr(&[&KILOMETRE, &METRE], 0),
+        "2 km 350 m"
+    );
+    assert_eq!(
+        val.display_compound(SI_LIST.get_list(), 0),
+        "2 kilometres 350 metres"
+    );
+    // Rounding carries up rather than giving 12 inches
+    let val = Measure::new(71.97, &INCH);
+    assert_eq!(val.display_compound_abbr(&[&FOOT, &INCH], 1), "6 ft");
+    assert_eq!(
+        val.display_compound_abbr(&[&FOOT, &INCH], 2),
+        "5 ft 11.97 in"
+    );
+    let val = Measure::new(1.0, &FOOT) + Measure::new(1.0, &INCH);
+    assert_eq!(val.display_compound(&[&FOOT, &INCH], 0), "1 foot 1 inch");
+    // Smaller than the largest unit
+    let val = Measure::new(0.75, &FOOT);
+    assert_eq!(val.display_compound_abbr(&[&YARD, &FOOT, &INCH], 0), "9 in");
+    assert_eq!(
+        Measure::new(0.0, &FOOT).display_compound_abbr(&[&FOOT, &INCH], 0),
+        "0 in"
+    );
+    assert_eq!(
+        Measure::new(-0.01, &FOOT).display_compound_abbr(&[&FOOT, &INCH], 0),
+        "0 in"
+    );
+    // Negative values keep the sign up front
+    let val = Measure::new(-1.5, &FOOT);
+    assert_eq!(val.display_compound_abbr(&[&FOOT, &INCH], 0), "-1 ft 6 in");
+    let parts = val.split_into_units(&[&FOOT, &INCH], 0);
+    assert_eq!(parts[0].get_val(), -1.0);
+    assert_eq!(parts[1].get_val(), -6.0);
+    // A runtime unit with a NaN factor does not panic
+    let broken = super::LengthUnit::new("br", "broken", "broken", crate::common::Float::NAN);
+    let parts = val.split_into_units(&[&FOOT, &broken], 0);
+    assert!(parts.iter().any(|part| part.get_val().is_nan()));
+}
+#[test]
+fn test_compound_display_equivalent_units() {
+    use super::systems::si_us::*;
+    // Equivalent units are only split into once, using the first given
+    let val = Measure::new(1.8, &METRE);
+    let parts = val.split_into_units(&[&METER, &MILLIMETRE, &METRE, &MILLIMETER], 0);
+    assert_eq!(parts.len(), 2);
+    assert!(parts[0].get_unit().is_identical(&METER));
+    assert!(parts[1].get_unit().is_identical(&MILLIMETRE));
+    assert_eq!(
+        val.display_compound(&[&METER, &MILLIMETRE, &METRE, &MILLIMETER], 0),
+        "1 meter 800 millimetres"
+    );
+    // The full list has both spellings of the metric units
+    let shown = val.display_compound_abbr(super::systems::ALL_LENGTH_LIST.get_list(), 1);
+    let abbrs: Vec<&str> = shown.split(' ').skip(1).step_by(2).collect();
+    for (i, abbr) in abbrs.iter().enumerate() {
+        assert!(!abbrs[i + 1..].contains(abbr), "{} repeats {}", shown, abbr);
+    }
+}
+#[test]
+fn test_best_unit() {
+    use super::systems::us::*;
+    use crate::BestUnitRule;
+    let rule = BestUnitRule::new();
+    let val = Measure::new(0.0042, &KILOMETRE).to_best_unit(&SI_LIST, &rule);
+    assert_eq!(val.display_abbr(1), "4.2m");
+    let val = Measure::new(15000.0, &MILLIMETRE).to_best_unit(&SI_LIST, &rule);
+    assert_eq!(val.display_abbr(0), "15m");
+    let val = Measure::new(2.5, &MILLIMETRE).to_best_unit(&SI_LIST, &rule);
+    assert_eq!(val.display_abbr(1), "2.5mm");
+    // Nothing fits, so the closest is used
+    let val = Measure::new(0.01, &MILLIMETRE).to_best_unit(&SI_LIST, &rule);
+    assert_eq!(val.display_abbr(2), "0.01mm");
+    let val = Measure::new(25000.0, &KILOMETRE).to_best_unit(&SI_LIST, &rule);
+    assert_eq!(val.display_abbr(0), "25000km");
+    // Another range
+    let rule = BestUnitRule::new().with_range(0.1, 100.0);
+    let val = Measure::new(250.0, &METRE).to_best_unit(&SI_LIST, &rule);
+    assert_eq!(val.display_abbr(2), "0.25km");
+    // Whole numbers
+    let rule = BestUnitRule::new().prefer_whole();
+    let val = Measure::new(1500.0, &METRE);
+    assert_eq!(val.best_unit(&SI_LIST, &rule).get_abbr(), "m");
+    assert_eq!(
+        val.best_unit(&SI_LIST, &BestUnitRule::new()).get_abbr(),
+        "km"
+    );
+    let val = Measure::new(2000.0, &METRE);
+    assert_eq!(val.best_unit(&SI_LIST, &rule).get_abbr(), "km");
+    let val = Measure::new(36.0, &INCH);
+    assert_eq!(val.best_unit(&US_LENGTH_LIST, &rule).get_abbr(), "yd");
+    // Preferred units are used when they fit
+    let rule = BestUnitRule::new().with_preferred(vec![&CENTIMETRE]);
+    let val = Measure::new(0.42, &METRE);
+    assert_eq!(val.best_unit(&SI_LIST, &rule).get_abbr(), "cm");
+    let val = Measure::new(42.0, &METRE);
+    assert_eq!(val.best_unit(&SI_LIST, &rule).get_abbr(), "m");
+    // A runtime unit with a NaN factor does not panic
+    let broken = super::LengthUnit::new("br", "broken", "broken", crate::common::Float::NAN);
+    let list = crate::unit_creation::UnitList::new("Broken", vec![&METRE, &broken]);
+    let val = Measure::new(42.0, &METRE);
+    assert_eq!(val.best_unit(&list, &BestUnitRule::new()).get_abbr(), "m");
+}
+#[cfg(feature = "rational")]
+#[test]
+fn test_exact() {
+    use super::systems::us::*;
+    use crate::common::{NotRational, Rational};
+    use crate::ExactMeasure;
+    use std::convert::TryFrom;
+    // 1 in is exactly 0.0254 m
+    let val = ExactMeasure::new(Rational::from_integer(1), &INCH);
+    assert_eq!(val.get_val_as(&METRE), Rational::new(254, 10000));
+    assert_eq!(val.get_val_as(&FOOT), Rational::new(1, 12));
+    // Round trips are lossless
+    let mut val = ExactMeasure::new(Rational::new(3, 7), &INCH);
+    val.convert_to(&METRE);
+    val.convert_to(&MILE);
+    val.convert_to(&INCH);
+    assert_eq!(val.get_val(), Rational::new(3, 7));
+    // From a float Measure, taken as the decimal it is written as
+    let val = ExactMeasure::try_from(Measure::new(0.75, &FOOT)).unwrap();
+    assert_eq!(val.get_val_as(&INCH), Rational::from_integer(9));
+    assert_eq!(val.to_number::<f64>().unwrap().get_val(), 0.75);
+    #[allow(deprecated)]
+    let float = val.to_measure();
+    assert_eq!(float.get_val(), 0.75);
+    // Floats with no exact ratio are rejected, not panicked on
+    let tiny = ExactMeasure::try_from(Measure::new(1e-40, &FOOT));
+    assert_eq!(tiny.err(), Some(NotRational(1e-40)));
+    assert!(ExactMeasure::try_from(Measure::new(1e40, &FOOT)).is_err());
+    assert!(ExactMeasure::try_from(Measure::new(f64::NAN, &FOOT)).is_err());
+    assert!(Measure::new(f64::INFINITY, &FOOT)
+        .to_number::<Rational>()
+        .is_none());
+    // Results past `i128` are caught by the checked conversion
+    let huge = ExactMeasure::new(Rational::from_integer(i128::MAX), &MILE);
+    assert_eq!(huge.checked_get_val_as(&INCH), None);
+    // Exact values have no neighbours, so any ULPs tolerance needs them equal
+    use crate::Tolerance;
+    let third = ExactMeasure::new(Rational::new(1, 3), &FOOT);
+    assert!(third.approx_eq(
+        &ExactMeasure::new(Rational::from_integer(4), &INCH),
+        Tolerance::Ulps(0)
+    ));
+    let near = ExactMeasure::new(Rational::new(1_000_000_001, 3_000_000_000), &FOOT);
+    assert!(!third.approx_eq(&near, Tolerance::Ulps(u64::MAX - 1)));
+    let mut total = ExactMeasure::new(Rational::from_integer(1), &YARD);
+    total.subtract_other(&val);
+    assert_eq!(total.get_val_as(&INCH), Rational::from_integer(27));
+    assert!(total == ExactMeasure::new(Rational::new(27, 12), &FOOT));
+}
+#[test]
+fn test_number_types() {
+    use super::systems::us::*;
+    // Single precision, for mesh data
+    let mut val: Measure<_, f32> = Measure::new(1.5, &METRE);
+    assert_eq!(val.get_val_as(&CENTIMETRE), 150.0);
+    val += Measure::new(50.0, &CENTIMETRE);
+    val *= 2.0;
+    assert_eq!(val.get_val(), 4.0);
+    assert_eq!(val.get_val_as(&FOOT).round_to(3), 13.123);
+    assert_eq!(val.display(1), "4.0 metres");
+    assert_eq!(format!("{:.2}", val), "4.00 m");
+    assert_eq!(val / Measure::new(2.0, &METRE), 2.0);
+    // Switching between the number types
+    let val = Measure::new(2.5, &INCH).to_number::<f32>().unwrap();
+    assert_eq!(val.get_val(), 2.5_f32);
+    assert_eq!(val.to_number::<f64>().unwrap().get_val(), 2.5);
+}
+#[cfg(feature = "rational")]
+#[test]
+fn test_rational_display() {
+    use super::systems::us::*;
+    use crate::common::Rational;
+    use crate::ExactMeasure;
+    let val = ExactMeasure::new(Rational::new(1, 3), &FOOT);
+    assert_eq!(format!("{}", val), "1/3 ft");
+    assert_eq!(format!("{:.3}", val), "0.333 ft");
+    assert_eq!(val.display(2), "0.33 feet");
+}
+#[test]
+fn test_compare() {
+    use super::systems::us::*;
+    use crate::Tolerance;
+    let foot = Measure::new(1.0, &FOOT);
+    let inches = Measure::new(12.0, &INCH);
+    assert!(foot.approx_eq(&inches, Tolerance::Absolute(1e-9)));
+    assert!(foot.approx_eq(&inches, Tolerance::Relative(1e-12)));
+    assert!(foot.approx_eq(&inches, Tolerance::Ulps(4)));
+    let more = Measure::new(12.1, &INCH);
+    assert!(!foot.approx_eq(&more, Tolerance::Absolute(0.001)));
+    assert!(foot.approx_eq(&more, Tolerance::Absolute(0.01)));
+    assert!(!foot.approx_eq(&more, Tolerance::Relative(0.001)));
+    assert!(!foot.approx_eq(&more, Tolerance::Ulps(1000)));
+    // ULPs are counted in the number type stored
+    let single: Measure<_, f32> = Measure::new(1.0, &FOOT);
+    let next = Measure::new(1.0 + f32::EPSILON, &FOOT);
+    assert!(single.approx_eq(&next, Tolerance::Ulps(1)));
+    assert!(!single.approx_eq(
+        &Measure::new(1.0 + 4.0 * f32::EPSILON, &FOOT),
+        Tolerance::Ulps(3)
+    ));
+    // Ordering across units
+    let metre = Measure::new(1.0, &METRE);
+    assert!(metre > Measure::new(3.0, &FOOT));
+    assert!(metre < Measure::new(40.0, &INCH));
+    assert!(Measure::new(999.0, &MILLIMETRE) <= metre);
+    assert_eq!(metre.min(more).get_unit().get_abbr(), "in");
+    assert_eq!(metre.max(more).get_unit().get_abbr(), "m");
+    let mut list = [metre, more, Measure::new(2.0, &YARD), foot];
+    list.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let sorted: Vec<&str> = list.iter().map(|val| val.get_unit().get_abbr()).collect();
+    assert_eq!(sorted, vec!["ft", "in", "m", "yd"]);
+    // Range checks
+    let low = Measure::new(10.0, &CENTIMETRE);
+    let high = Measure::new(2.0, &FOOT);
+    assert_eq!(metre.clamp(low, high).display_abbr(0), "2ft");
+    assert_eq!(
+        Measure::new(5.0, &CENTIMETRE)
+            .clamp(low, high)
+            .display_abbr(0),
+        "10cm"
+    );
+    assert_eq!(inches.clamp(low, high).display_abbr(0), "12in");
+    // The bounds may be in different units, as long as they are in order
+    assert_eq!(inches.clamp(low, metre).display_abbr(0), "12in");
+}
+#[test]
+#[should_panic]
+fn test_clamp_reversed() {
+    use super::systems::us::*;
+    let low = Measure::new(10.0, &CENTIMETRE);
+    let high = Measure::new(2.0, &FOOT);
+    Measure::new(1.0, &METRE).clamp(high, low);
+}
@@ -1,6 +1,7 @@
 use super::systems::si::*;
 use super::LengthUnit;
-use crate::base_types::UnitTrait;
+use crate::base_types::{Measure, UnitList, UnitTrait};
+use crate::common::test_util::{run_test, TestStruct};
 use crate::common::*;
 #[test]
 fn test_si_list() {
@@ -66,34 +67,309 @@ fn test_si_list() {
         },
     ];
     for val in test_list {
-        run_test(val);
+        run_test(&SI_LIST, val);
     }
 }
-fn run_test(val: TestStruct) {
-    for input in val.inputs {
-        let res = SI_LIST.parse_str(input);
-        match res {
-            Some(res) => {
-                let val = val.output.expect(format!("Input:{}", input).as_str());
-                assert_eq!(val.0, res.0);
-                let val = val.1;
-                let res = res.1;
-                // Ensure these match exactly
-                assert_eq!(val.get_singular(), res.get_singular());
-                assert_eq!(val.get_plural(), res.get_plural());
-                assert_eq!(val.get_abbr(), res.get_abbr());
-                assert_eq!(val.in_base(), res.in_base());
-            }
-            None => match val.output {
-                Some(_) => assert!(false, "Value not found"),
-                None => (),
-            },
-        }
+#[test]
+fn test_parse_errors() {
+    assert_eq!(SI_LIST.try_parse_str("").err(), Some(ParseError::Empty));
+    assert_eq!(SI_LIST.try_parse_str("   ").err(), Some(ParseError::Empty));
+    assert_eq!(
+        SI_LIST.try_parse_str("15.5.5 km").err(),
+        Some(ParseError::BadNumber(String::from("15.5.5")))
+    );
+    assert_eq!(
+        SI_LIST.try_parse_str("NaN km").err(),
+        Some(ParseError::BadNumber(String::from("NaN km")))
+    );
+    assert_eq!(
+        SI_LIST.try_parse_str("12.5 mi").err(),
+        Some(ParseError::UnknownUnit(String::from("mi")))
+    );
+    assert_eq!(
+        SI_LIST.try_parse_str("12 $$").err(),
+        Some(ParseError::UnknownUnit(String::from("$$")))
+    );
+    assert_eq!(
+        SI_LIST.try_parse_str("12.5 mi").err().unwrap().to_string(),
+        "unknown unit 'mi'"
+    );
+    // The same string for two different units
+    let mystery = LengthUnit::new("mm", "mystery metre", "mystery metres", 1.0);
+    let list = UnitList::new("Mixed", vec![&MILLIMETRE, &mystery]);
+    assert_eq!(
+        list.try_parse_str("12 mm").err(),
+        Some(ParseError::AmbiguousUnit(String::from("mm")))
+    );
+    // Without the error, the first match is taken
+    let res = list.parse_str("12 mm").unwrap();
+    assert_eq!(res.1.get_singular(), MILLIMETRE.get_singular());
+    let res = Measure::from_literal(&list, "12 mm").unwrap();
+    assert_eq!(res.get_unit().get_singular(), MILLIMETRE.get_singular());
+    // Only one of them has this name
+    let res = list.try_parse_str("12 mystery metres").unwrap();
+    assert_eq!(res.1.in_base(), 1.0);
+    // The ok path is the same as parse_str
+    let res = SI_LIST.try_parse_str("12.5 km").unwrap();
+    assert_eq!(res.0, 12.5);
+    assert_eq!(res.1.get_abbr(), "km");
+}
+#[test]
+fn test_compound() {
+    use super::systems::us::*;
+    // Everything ends up in the last unit
+    let res = US_LENGTH_LIST.parse_str("5 ft 11 in").unwrap();
+    assert_eq!(res.0.round_to(9), 71.0);
+    assert_eq!(res.1.get_abbr(), INCH.get_abbr());
+    let res = US_LENGTH_LIST.parse_str("5'11\"").unwrap();
+    assert_eq!(res.0.round_to(9), 71.0);
+    assert_eq!(res.1.get_abbr(), INCH.get_abbr());
+    let res = US_LENGTH_LIST.parse_str("5 feet, 11.5 inches").unwrap();
+    assert_eq!(res.0.round_to(9), 71.5);
+    let res = US_LENGTH_LIST.parse_str("3 mi 200 yd").unwrap();
+    assert_eq!(res.0.round_to(2), 5480.0);
+    assert_eq!(res.1.get_abbr(), YARD.get_abbr());
+    // The sign applies to everything
+    let res = US_LENGTH_LIST.parse_str("-1 ft 6 in").unwrap();
+    assert_eq!(res.0.round_to(9), -18.0);
+    // Symbols alone work as well
+    let res = US_LENGTH_LIST.parse_str("6'").unwrap();
+    assert_eq!(res.0, 6.0);
+    assert_eq!(res.1.get_abbr(), FOOT.get_abbr());
+    let res = SI_LIST.parse_str("1 m 20 cm").unwrap();
+    assert_eq!(res.0, 120.0);
+    assert_eq!(res.1.get_abbr(), CENTIMETRE.get_abbr());
+    // The errors come from the term at fault
+    assert_eq!(
+        US_LENGTH_LIST.try_parse_str("5 ft 11 cm").err(),
+        Some(ParseError::UnknownUnit(String::from("cm")))
+    );
+    assert_eq!(
+        US_LENGTH_LIST.try_parse_str("5 ft -11 in").err(),
+        Some(ParseError::BadNumber(String::from("-11")))
+    );
+    assert_eq!(
+        SI_LIST.try_parse_str("1 m 20").err(),
+        Some(ParseError::UnknownUnit(String::new()))
+    );
+    assert!(SI_LIST.parse_str("1 m 20").is_none());
+}
+#[test]
+fn test_number_grammar() {
+    use super::systems::us::*;
+    let res = SI_LIST.parse_str("1.5e3 m").unwrap();
+    assert_eq!(res.0, 1500.0);
+    let res = SI_LIST.parse_str("−2.5 km").unwrap();
+    assert_eq!(res.0, -2.5);
+    let res = US_LENGTH_LIST.parse_str("1,250 ft").unwrap();
+    assert_eq!(res.0, 1250.0);
+    assert_eq!(res.1.get_abbr(), FOOT.get_abbr());
+    let res = US_LENGTH_LIST.parse_str("3 1/2 in").unwrap();
+    assert_eq!(res.0, 3.5);
+    let res = US_LENGTH_LIST.parse_str("½ in").unwrap();
+    assert_eq!(res.0, 0.5);
+    // Fractions work in compounds too
+    let res = US_LENGTH_LIST.parse_str("5 ft 11 1/2 in").unwrap();
+    assert_eq!(res.0.round_to(9), 71.5);
+    assert_eq!(
+        US_LENGTH_LIST.try_parse_str("1/0 in").err(),
+        Some(ParseError::BadNumber(String::from("1/0")))
+    );
+}
+#[test]
+fn test_prefixes() {
+    let res = SI_PREFIXED_LENGTH_LIST.parse_str("12 µm").unwrap();
+    assert_eq!(res.0, 12.0);
+    assert_eq!(res.1.get_abbr(), "µm");
+    assert_eq!(res.1.get_singular(), "micrometre");
+    assert_eq!(res.1.in_base(), 1_000_000.0);
+    // The Greek mu is treated as the micro sign
+    let res = SI_PREFIXED_LENGTH_LIST.parse_str("12 μm").unwrap();
+    assert_eq!(res.1.get_abbr(), "µm");
+    // Prefix symbols are case sensitive
+    let res = SI_PREFIXED_LENGTH_LIST.parse_str("1.5 Gm").unwrap();
+    assert_eq!(res.1.get_plural(), "gigametres");
+    assert_eq!(res.1.in_base(), 1e-9);
+    let res = SI_PREFIXED_LENGTH_LIST.parse_str("3 Mm").unwrap();
+    assert_eq!(res.1.get_singular(), "megametre");
+    let res = SI_PREFIXED_LENGTH_LIST.parse_str("3 mm").unwrap();
+    assert_eq!(res.1.get_singular(), "millimetre");
+    // The 2022 prefixes are there as well
+    let res = SI_PREFIXED_LENGTH_LIST.parse_str("2 quettametres").unwrap();
+    assert_eq!(res.1.get_abbr(), "Qm");
+    assert_eq!(res.1.in_base(), 1e-30);
+    assert_eq!(
+        SI_PREFIXED_LENGTH_LIST.find("rm").unwrap().get_singular(),
+        "rontometre"
+    );
+    assert_eq!(SI_PREFIXED_LENGTH_LIST.get("da").unwrap().get_abbr(), "dam");
+    assert!(SI_PREFIXED_LENGTH_LIST.get("x").is_none());
+    assert_eq!(
+        SI_PREFIXED_LENGTH_LIST.try_parse_str("3 xm").err(),
+        Some(ParseError::UnknownUnit(String::from("xm")))
+    );
+    // Parsing a length falls back to the prefixes
+    let res: crate::Measure<LengthUnit> = "5 nm".parse().unwrap();
+    assert_eq!(res.get_unit().get_singular(), "nanometre");
+    assert_eq!(res.get_val_as(&METRE).round_to(15), 5e-9);
+}
+#[test]
+fn test_identity() {
+    use super::systems::si_us::*;
+    // Other spellings are the same size, but not the same unit
+    assert!(METRE != METER);
+    assert!(METRE.is_equivalent(&METER));
+    assert!(!METRE.is_equivalent(&KILOMETRE));
+    assert!(METRE == LengthUnit::new("m", "metre", "metres", 1.0));
+    // Identity is the id alone, not the spelling or size
+    assert!(METRE != LengthUnit::new("m", "metre", "metres", 1.0).with_id("survey metre"));
+    assert!(METRE == LengthUnit::new("M", "Metre", "Metres", 1.0).with_id("metre"));
+    assert_eq!(
+        SI_PREFIXED_LENGTH_LIST.get("k").unwrap().get_id(),
+        "kilometre"
+    );
+    assert!(*SI_PREFIXED_LENGTH_LIST.get("k").unwrap() == KILOMETRE);
+    // Merging keeps the other spellings, but not the true duplicates
+    let mut list = UnitList::new("Merged", SI_LIST.get_list().clone());
+    list.merge_other(SI_US_LIST.get_list());
+    list.merge_other(SI_LIST.get_list());
+    assert_eq!(list.get_list().len(), 10);
+    let res = list.parse_str("12 meters").unwrap();
+    assert_eq!(res.1.get_singular(), "meter");
+    // Both spellings share `mm`, which is not ambiguous as they are the same size
+    let res = list.parse_str("12 mm").unwrap();
+    assert_eq!(res.1.in_base(), 1000.0);
+    let res = super::systems::ALL_LENGTH_LIST
+        .parse_str("3 kilometers")
+        .unwrap();
+    assert!(res.1 == &KILOMETER);
+}
+#[test]
+fn test_registry() {
+    use crate::registry::{RegisteredUnit, UnitRegistry};
+    let registry = LengthUnit::registry();
+    assert_eq!(
+        registry.get_systems(),
+        vec!["SI", "SI_US", "US", "SI Prefixed"]
+    );
+    // Any system, by abbreviation, name or symbol
+    assert_eq!(registry.lookup("ft").unwrap().get_singular(), "foot");
+    assert_eq!(registry.lookup("meters").unwrap().get_abbr(), "m");
+    assert_eq!(registry.lookup("\"").unwrap().get_singular(), "inch");
+    assert_eq!(registry.lookup("Gm").unwrap().get_singular(), "gigametre");
+    assert_eq!(
+        registry.lookup("furlong").err(),
+        Some(ParseError::UnknownUnit(String::from("furlong")))
+    );
+    // Where a name is found more than once
+    let found: Vec<String> = registry
+        .find_all("mm")
+        .into_iter()
+        .map(|(system, _)| system)
+        .collect();
+    assert_eq!(found, vec!["SI", "SI_US", "SI Prefixed"]);
+    let ambiguities = registry.ambiguities();
+    assert!(ambiguities.contains(&(String::from("mm"), found)));
+    assert!(!ambiguities.iter().any(|(name, _)| name == "ft"));
+    let val = registry.try_parse_str("5 ft 11 in").unwrap();
+    assert_eq!(val.get_val().round_to(9), 71.0);
+    // Applications may add their own units and systems
+    lazy_static! {
+        static ref FURLONG: LengthUnit =
+            LengthUnit::new("fur", "furlong", "furlongs", 1.0 / 201.168);
+        static ref LEAGUE: LengthUnit = LengthUnit::new("lea", "league", "leagues", 1.0 / 4828.032);
+        static ref REGISTRY: UnitRegistry<LengthUnit> = UnitRegistry::with_systems(&[&SI_LIST]);
     }
+    REGISTRY.register_unit("Old", &FURLONG);
+    REGISTRY.register_unit("Old", &LEAGUE);
+    REGISTRY.register_unit("Old", &FURLONG);
+    REGISTRY.register_system(&UnitList::new("Again", vec![&METRE]));
+    assert_eq!(REGISTRY.get_systems(), vec!["SI", "Old", "Again"]);
+    assert_eq!(REGISTRY.get_units().get_list().len(), 7);
+    assert_eq!(REGISTRY.lookup("furlongs").unwrap().get_abbr(), "fur");
+    // A different unit with the same name is ambiguous
+    lazy_static! {
+        static ref MYSTERY: LengthUnit = LengthUnit::new("lea", "mystery", "mysteries", 1.0);
+    }
+    REGISTRY.register_unit("Mystery", &MYSTERY);
+    assert_eq!(
+        REGISTRY.lookup("lea").err(),
+        Some(ParseError::AmbiguousUnit(String::from("lea")))
+    );
+    // Units defined at runtime are owned by the registry
+    assert!(REGISTRY.lookup("chains").is_err());
+    REGISTRY.register_owned_unit(
+        "Old",
+        LengthUnit::new("ch", "chain", "chains", 1.0 / 20.1168),
+    );
+    assert_eq!(REGISTRY.lookup("chains").unwrap().get_abbr(), "ch");
+    let val: crate::Measure<'static, LengthUnit> = REGISTRY.try_parse_str("10 ch").unwrap();
+    assert_eq!(val.get_val_as(&METRE).round_to(9), 201.168);
+    // Including by a registry that is not global
+    let registry = UnitRegistry::new();
+    registry.register_owned_unit("Old", LengthUnit::new("rd", "rod", "rods", 1.0 / 5.0292));
+    let val = registry.try_parse_str("2 rods").unwrap();
+    assert_eq!(val.get_unit().get_singular(), "rod");
+    assert_eq!(registry.get_units().get_list().len(), 1);
+    // The units do not borrow the registry, so more may be added while using them
+    for unit in registry.get_units().get_list() {
+        let name = format!("double {}", unit.get_singular());
+        let double = LengthUnit::new("2rd", name.clone(), name, unit.in_base() / 2.0);
+        registry.register_owned_unit("Doubled", double);
+    }
+    assert_eq!(registry.get_units().get_list().len(), 2);
+    drop(registry);
+    assert_eq!(val.get_val_as(&METRE).round_to(9), 10.0584);
 }
-struct TestStruct {
-    /// A list of inputs that equal the output
-    inputs: Vec<&'static str>,
-    /// The output of each operation
-    output: Option<(Float, &'static LengthUnit)>,
+#[test]
+fn test_case_and_aliases() {
+    use super::systems::us::*;
+    use super::systems::ALL_LENGTH_LIST;
+    use crate::unit_creation::CaseMatching;
+    // Names and aliases in any case
+    let res = ALL_LENGTH_LIST.parse_str("3 Feet").unwrap();
+    assert!(res.1 == &FOOT);
+    assert!(ALL_LENGTH_LIST.parse_str("3 FT.").unwrap().1 == &FOOT);
+    assert!(ALL_LENGTH_LIST
+        .parse_str("12 mtr")
+        .unwrap()
+        .1
+        .is_equivalent(&METRE));
+    assert!(ALL_LENGTH_LIST.parse_str("12 Metres").unwrap().1 == &METRE);
+    assert!(ALL_LENGTH_LIST.parse_str("2 yds").unwrap().1 == &YARD);
+    assert!(ALL_LENGTH_LIST.parse_str("5 ft. 11 in.").unwrap().1 == &INCH);
+    // Abbreviations must be in the exact case by default
+    assert!(ALL_LENGTH_LIST.parse_str("12 M").is_none());
+    let list = UnitList::new("US", US_LENGTH_LIST.get_list().clone());
+    assert_eq!(list.get_case_matching(), CaseMatching::Names);
+    assert!(list.parse_str("3 FEET").is_some());
+    assert!(list.parse_str("3 FT").is_none());
+    // Or in any case when nothing else matches, if the list allows it
+    let all = UnitList::new("All", ALL_LENGTH_LIST.get_list().clone())
+        .with_case_matching(CaseMatching::Lenient);
+    assert!(all.parse_str("12 M").unwrap().1.is_equivalent(&METRE));
+    assert!(all.parse_str("12 KM").unwrap().1.is_equivalent(&KILOMETRE));
+    let prefixed = SI_PREFIXED_LENGTH_LIST
+        .list()
+        .with_case_matching(CaseMatching::Lenient);
+    assert_eq!(
+        prefixed.parse_str("1 Mm").unwrap().1.get_singular(),
+        "megametre"
+    );
+    assert_eq!(
+        prefixed.parse_str("1 mm").unwrap().1.get_singular(),
+        "millimetre"
+    );
+    assert_eq!(
+        prefixed.try_parse_str("1 MM").err(),
+        Some(ParseError::AmbiguousUnit(String::from("MM")))
+    );
+    // Stricter matching
+    let list = list.with_case_matching(CaseMatching::Exact);
+    assert!(list.parse_str("3 feet").is_some());
+    assert!(list.parse_str("3 Feet").is_none());
+    // Runtime units may have aliases as well
+    let cubit = LengthUnit::new("cbt", "cubit", "cubits", 1.0 / 0.4572).with_alias("ell");
+    let list = UnitList::new("Old", vec![&cubit]);
+    assert_eq!(list.parse_str("4 Ell").unwrap().0, 4.0);
 }
@@ -0,0 +1,116 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use std::borrow::Cow;
+/// The different pre-made unit systems for Mass
+pub mod systems;
+#[cfg(test)]
+mod test_measure;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for a Mass entry.
+///
+/// The base unit for Mass is `Gram`.
+pub struct MassUnit {
+    /// Identifies this unit among the others of its kind (ex: `troy ounce`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `lbs`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many of this unit does it take to make a gram
+    in_gram: Float,
+    /// The exact `in_gram` as (numerator, denominator), when `in_gram` is rounded
+    exact: Option<(i64, i64)>,
+}
+impl MassUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `MassUnit::new("st", "stone", "stone", 1.0 / 6350.29318)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_gram: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_gram,
+            exact: None,
+        }
+    }
+    /// Sets the exact `in_gram` as a ratio (ex: `100000 / 45359237` for the pound), for exact conversions
+    pub fn with_exact(mut self, numerator: i64, denominator: i64) -> Self {
+        self.exact = Some((numerator, denominator));
+        self
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `lbs` for pounds)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for MassUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_gram
+    }
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        self.exact
+    }
+}
+impl LinearUnit for MassUnit {}
+// Derive the prefixed units (ex: `kg` from `g`)
+impl PrefixableUnit for MassUnit {
+    fn with_prefix(&self, prefix: &Prefix) -> Self {
+        Self::new(
+            prefix.prefix_abbr(&self.abbr),
+            prefix.prefix_name(&self.singular),
+            prefix.prefix_name(&self.plural),
+            prefix.prefix_in_base(self.in_gram),
+        )
+        .with_id(prefix.prefix_name(&self.id))
+    }
+}
+// Register the built-in systems
+impl RegisteredUnit for MassUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::MASS_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for MassUnit {
+    fn eq(&self, other: &MassUnit) -> bool {
+        self.is_identical(other)
+    }
+}
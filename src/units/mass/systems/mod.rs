@@ -0,0 +1,18 @@
+use super::MassUnit;
+use crate::registry::UnitRegistry;
+/// Module with SI units (Gram and such)
+pub mod si;
+/// Module with troy units (Troy Ounce and such)
+pub mod troy;
+/// Module with the United States customary avoirdupois units (Pound and such)
+pub mod us;
+
+lazy_static! {
+    /// Every built-in Mass system, for looking units up by name
+    pub static ref MASS_REGISTRY: UnitRegistry<MassUnit> = UnitRegistry::with_systems(&[
+        &si::SI_MASS_LIST,
+        &us::US_MASS_LIST,
+        &troy::TROY_LIST,
+        &si::SI_PREFIXED_MASS_LIST.list(),
+    ]);
+}
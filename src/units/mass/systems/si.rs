@@ -0,0 +1,50 @@
+use super::super::MassUnit;
+use crate::prefix::PrefixedUnits;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static MILLIGRAM: MassUnit = MassUnit {
+    id: Cow::Borrowed("milligram"),
+    abbr: Cow::Borrowed("mg"),
+    singular: Cow::Borrowed("milligram"),
+    plural: Cow::Borrowed("milligrams"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1000.0,
+    exact: None,
+};
+
+pub static GRAM: MassUnit = MassUnit {
+    id: Cow::Borrowed("gram"),
+    abbr: Cow::Borrowed("g"),
+    singular: Cow::Borrowed("gram"),
+    plural: Cow::Borrowed("grams"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1.0,
+    exact: None,
+};
+
+pub static KILOGRAM: MassUnit = MassUnit {
+    id: Cow::Borrowed("kilogram"),
+    abbr: Cow::Borrowed("kg"),
+    singular: Cow::Borrowed("kilogram"),
+    plural: Cow::Borrowed("kilograms"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 0.001,
+    exact: None,
+};
+
+pub static TONNE: MassUnit = MassUnit {
+    id: Cow::Borrowed("tonne"),
+    abbr: Cow::Borrowed("t"),
+    singular: Cow::Borrowed("tonne"),
+    plural: Cow::Borrowed("tonnes"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 0.000001,
+    exact: None,
+};
+
+lazy_static! {
+    pub static ref SI_MASS_LIST: UnitList<'static, MassUnit> =
+        UnitList::new("SI", vec!(&MILLIGRAM, &GRAM, &KILOGRAM, &TONNE,),);
+    /// Every SI prefix of the Gram (ex: `µg`), found without listing each one
+    pub static ref SI_PREFIXED_MASS_LIST: PrefixedUnits<MassUnit> = PrefixedUnits::new(&GRAM);
+}
@@ -0,0 +1,50 @@
+use super::super::MassUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static GRAIN: MassUnit = MassUnit {
+    id: Cow::Borrowed("grain"),
+    abbr: Cow::Borrowed("gr"),
+    singular: Cow::Borrowed("grain"),
+    plural: Cow::Borrowed("grains"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1.0 / 0.06479891,
+    exact: Some((100000000, 6479891)),
+};
+
+pub static PENNYWEIGHT: MassUnit = MassUnit {
+    id: Cow::Borrowed("pennyweight"),
+    abbr: Cow::Borrowed("dwt"),
+    singular: Cow::Borrowed("pennyweight"),
+    plural: Cow::Borrowed("pennyweights"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1.0 / 1.55517384,
+    exact: Some((12500000, 19439673)),
+};
+
+pub static TROY_OUNCE: MassUnit = MassUnit {
+    id: Cow::Borrowed("troy ounce"),
+    abbr: Cow::Borrowed("ozt"),
+    singular: Cow::Borrowed("troy ounce"),
+    plural: Cow::Borrowed("troy ounces"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1.0 / 31.1034768,
+    exact: Some((625000, 19439673)),
+};
+
+pub static TROY_POUND: MassUnit = MassUnit {
+    id: Cow::Borrowed("troy pound"),
+    abbr: Cow::Borrowed("lbt"),
+    singular: Cow::Borrowed("troy pound"),
+    plural: Cow::Borrowed("troy pounds"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1.0 / 373.2417216,
+    exact: Some((156250, 58319019)),
+};
+
+lazy_static! {
+    /// The list of Troy Mass units are stored here
+    pub static ref TROY_LIST: UnitList<'static, MassUnit> = UnitList::new(
+        "Troy",
+        vec!(&GRAIN, &PENNYWEIGHT, &TROY_OUNCE, &TROY_POUND,),
+    );
+}
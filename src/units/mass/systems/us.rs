@@ -0,0 +1,41 @@
+use super::super::MassUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static OUNCE: MassUnit = MassUnit {
+    id: Cow::Borrowed("ounce"),
+    abbr: Cow::Borrowed("oz"),
+    singular: Cow::Borrowed("ounce"),
+    plural: Cow::Borrowed("ounces"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("oz.")]),
+    in_gram: 1.0 / 28.349523125,
+    exact: Some((1600000, 45359237)),
+};
+
+pub static POUND: MassUnit = MassUnit {
+    id: Cow::Borrowed("pound"),
+    abbr: Cow::Borrowed("lb"),
+    singular: Cow::Borrowed("pound"),
+    plural: Cow::Borrowed("pounds"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("lb."), Cow::Borrowed("lbs")]),
+    in_gram: 1.0 / 453.59237,
+    exact: Some((100000, 45359237)),
+};
+
+/// The US (short) ton of 2000 pounds
+pub static SHORT_TON: MassUnit = MassUnit {
+    id: Cow::Borrowed("ton"),
+    abbr: Cow::Borrowed("tn"),
+    singular: Cow::Borrowed("ton"),
+    plural: Cow::Borrowed("tons"),
+    aliases: Cow::Borrowed(&[]),
+    in_gram: 1.0 / 907184.74,
+    exact: Some((50, 45359237)),
+};
+
+lazy_static! {
+    /// The list of US (avoirdupois) Mass units are stored here
+    pub static ref US_MASS_LIST: UnitList<'static, MassUnit> = UnitList::new(
+        "US",
+        vec!(&OUNCE, &POUND, &SHORT_TON,),
+    );
+}
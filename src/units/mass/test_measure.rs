@@ -0,0 +1,48 @@
+use super::systems::{si::*, troy::*, us::*};
+use crate::common::{ParseError, RoundTo};
+use crate::Measure;
+
+#[test]
+fn convert() {
+    let mut val = Measure::new(2.5, &KILOGRAM);
+    assert_eq!(val.get_val_as(&GRAM), 2500.0);
+    assert_eq!(val.get_val_as(&MILLIGRAM), 2500000.0);
+    val.convert_to(&TONNE);
+    assert_eq!(val.get_val(), 0.0025);
+    // Avoirdupois conversions
+    let pound = Measure::new(1.0, &POUND);
+    assert_eq!(pound.get_val_as(&GRAM).round_to(5), 453.59237);
+    assert_eq!(pound.get_val_as(&OUNCE).round_to(6), 16.0);
+    let ton = Measure::new(1.0, &SHORT_TON);
+    assert_eq!(ton.get_val_as(&POUND).round_to(4), 2000.0);
+    // Troy conversions
+    let troy = Measure::new(1.0, &TROY_POUND);
+    assert_eq!(troy.get_val_as(&TROY_OUNCE).round_to(6), 12.0);
+    assert_eq!(troy.get_val_as(&PENNYWEIGHT).round_to(5), 240.0);
+    assert_eq!(troy.get_val_as(&GRAIN).round_to(3), 5760.0);
+    assert_eq!(
+        Measure::new(7000.0, &GRAIN).get_val_as(&POUND).round_to(6),
+        1.0
+    );
+}
+#[test]
+fn from_literal() {
+    let val = Measure::from_literal(&US_MASS_LIST, "12 lb").unwrap();
+    assert_eq!(val.get_val(), 12.0);
+    assert_eq!(val.display(0), "12 pounds");
+    assert_eq!(val.get_val_as(&KILOGRAM).round_to(4), 5.4431);
+    assert!(Measure::from_literal(&US_MASS_LIST, "12 kg").is_none());
+}
+#[test]
+fn try_from_literal() {
+    let val = Measure::try_from_literal(&SI_MASS_LIST, "2.5 kg").unwrap();
+    assert_eq!(val.get_val_as(&GRAM), 2500.0);
+    let err = Measure::try_from_literal(&SI_MASS_LIST, "2.5 lb")
+        .err()
+        .unwrap();
+    assert_eq!(err, ParseError::UnknownUnit(String::from("lb")));
+    let err = Measure::try_from_literal(&SI_MASS_LIST, "2..5 kg")
+        .err()
+        .unwrap();
+    assert_eq!(err, ParseError::BadNumber(String::from("2..5")));
+}
@@ -0,0 +1,87 @@
+use super::systems::{si::*, troy::*, us::*};
+use crate::base_types::UnitTrait;
+use crate::common::test_util::{run_test, TestStruct};
+#[test]
+fn test_si_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["12.5 g", "12.5 gram", "12.5 grams", "12.5grams"],
+            output: Some((12.5, &GRAM)),
+        },
+        TestStruct {
+            inputs: vec!["3 kg", "3 kilogram", "3.0 kilograms"],
+            output: Some((3.0, &KILOGRAM)),
+        },
+        TestStruct {
+            inputs: vec!["0.5 t", ".5 tonne", "0.50 tonnes"],
+            output: Some((0.5, &TONNE)),
+        },
+        // Test the thing(s) that should not be
+        TestStruct {
+            inputs: vec!["12 lb", "12.A g", "12 ozt"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&SI_MASS_LIST, val);
+    }
+}
+#[test]
+fn test_us_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["12 lb", "12 pound", "12 pounds"],
+            output: Some((12.0, &POUND)),
+        },
+        TestStruct {
+            inputs: vec!["4.5 oz", "4.5 ounce", "4.5 ounces"],
+            output: Some((4.5, &OUNCE)),
+        },
+        TestStruct {
+            inputs: vec!["2 tn", "2 ton", "2 tons"],
+            output: Some((2.0, &SHORT_TON)),
+        },
+        TestStruct {
+            inputs: vec!["12 kg", "12 ozt"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&US_MASS_LIST, val);
+    }
+}
+#[test]
+fn test_troy_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["1 ozt", "1 troy ounce", "1 troy ounces"],
+            output: Some((1.0, &TROY_OUNCE)),
+        },
+        TestStruct {
+            inputs: vec!["20 dwt", "20 pennyweight", "20 pennyweights"],
+            output: Some((20.0, &PENNYWEIGHT)),
+        },
+        TestStruct {
+            inputs: vec!["7000 gr", "7000 grains"],
+            output: Some((7000.0, &GRAIN)),
+        },
+        TestStruct {
+            inputs: vec!["1 oz", "1 lb"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&TROY_LIST, val);
+    }
+}
+#[test]
+fn test_prefixes() {
+    let res = SI_PREFIXED_MASS_LIST.parse_str("250 µg").unwrap();
+    assert_eq!(res.1.get_singular(), "microgram");
+    let res = SI_PREFIXED_MASS_LIST.parse_str("2 kg").unwrap();
+    assert!(res.1 == &KILOGRAM);
+    // Another name for the same size
+    let res = SI_PREFIXED_MASS_LIST.parse_str("3 Mg").unwrap();
+    assert!(res.1 != &TONNE);
+    assert!(res.1.is_equivalent(&TONNE));
+}
@@ -1,3 +1,24 @@
+/// Values of any dimension, parsed without knowing the dimension in advance.  
+/// EX:
+/// - `"12 kg".parse::<AnyMeasure>()` gives a Mass
+pub mod any;
+/// Units for Area.  
+/// The Base Unit for Area is the Square Metre.  
+/// EX:
+/// - Square Metre
+/// - Acre
+/// - Hectare
+pub mod area;
+/// Operators between dimensions (ex: Length × Length = Area)
+mod derived;
+/// Units for Information (data sizes and bandwidth).  
+/// The Base Unit for Information is the Byte.  
+/// EX:
+/// - Byte
+/// - Gigabyte
+/// - Gibibyte
+/// - Megabit
+pub mod information;
 /// Units for Length.  
 /// The Base Unit for Length is the Metre.  
 /// EX:
@@ -5,3 +26,38 @@
 /// - Feet
 /// - Lightyears
 pub mod length;
+/// Units for Mass.  
+/// The Base Unit for Mass is the Gram.  
+/// EX:
+/// - Gram
+/// - Pound
+/// - Troy Ounce
+pub mod mass;
+/// Units for Temperature.  
+/// The Base Unit for Temperature is the Kelvin.  
+/// EX:
+/// - Kelvin
+/// - Degree Celsius
+/// - Degree Fahrenheit
+pub mod temperature;
+/// Units for Time.  
+/// The Base Unit for Time is the Second.  
+/// EX:
+/// - Second
+/// - Hour
+/// - Julian Year
+pub mod time;
+/// Units for Velocity.  
+/// The Base Unit for Velocity is the Metre per Second.  
+/// EX:
+/// - Metre per Second
+/// - Kilometre per Hour
+/// - Mile per Hour
+pub mod velocity;
+/// Units for Volume.  
+/// The Base Unit for Volume is the Litre.  
+/// EX:
+/// - Litre
+/// - Cubic Metre
+/// - Gallon
+pub mod volume;
@@ -0,0 +1,246 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use crate::Measure;
+use std::borrow::Cow;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+/// The different pre-made unit systems for Temperature
+pub mod systems;
+#[cfg(test)]
+mod test_measure;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for an absolute Temperature entry.
+///
+/// The base unit for Temperature is `Kelvin`.
+///
+/// Absolute temperatures cannot be added together.
+/// Subtracting two of them gives a `TemperatureDeltaUnit` measure,
+/// which can then be added to or subtracted from an absolute temperature.
+pub struct TemperatureUnit {
+    /// Identifies this unit among the others of its kind (ex: `degree Celsius`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `deg F`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many degrees of this unit does it take to make a kelvin
+    in_kelvin: Float,
+    /// The value of this unit at absolute zero
+    offset: Float,
+    /// The unit used for differences between two of these temperatures
+    delta: Cow<'static, TemperatureDeltaUnit>,
+}
+impl TemperatureUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// The delta may be a built-in unit (`&delta::KELVIN`) or one defined along with this one:
+    /// `TemperatureUnit::new("°Ré", "degree Réaumur", "degrees Réaumur", 0.8, -218.52, reaumur_delta)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_kelvin: Float,
+        offset: Float,
+        delta: impl Into<Cow<'static, TemperatureDeltaUnit>>,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_kelvin,
+            offset,
+            delta: delta.into(),
+        }
+    }
+    /// Returns the unit used for differences between two of these temperatures
+    pub fn get_delta(&self) -> &TemperatureDeltaUnit {
+        &self.delta
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `deg F` for degrees Fahrenheit)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for TemperatureUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_kelvin
+    }
+    fn offset(&self) -> Float {
+        self.offset
+    }
+}
+// Register the built-in systems
+impl RegisteredUnit for TemperatureUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::TEMPERATURE_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for TemperatureUnit {
+    fn eq(&self, other: &TemperatureUnit) -> bool {
+        self.is_identical(other)
+    }
+}
+/// The struct that specifies the unit for a Temperature difference.
+///
+/// The base unit for Temperature differences is the `Kelvin`.
+#[derive(Clone)]
+pub struct TemperatureDeltaUnit {
+    /// Identifies this unit among the others of its kind (ex: `kelvin`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `Δ°F`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many degrees of this unit does it take to make a kelvin
+    in_kelvin: Float,
+}
+impl TemperatureDeltaUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `TemperatureDeltaUnit::new("°Ré", "degree Réaumur", "degrees Réaumur", 0.8)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_kelvin: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_kelvin,
+        }
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `Δ°F` for degrees Fahrenheit)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for TemperatureDeltaUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_kelvin
+    }
+}
+impl LinearUnit for TemperatureDeltaUnit {}
+// Register the built-in systems
+impl RegisteredUnit for TemperatureDeltaUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::DELTA_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for TemperatureDeltaUnit {
+    fn eq(&self, other: &TemperatureDeltaUnit) -> bool {
+        self.is_identical(other)
+    }
+}
+
+// A built-in delta is borrowed by the temperature units using it
+impl From<&'static TemperatureDeltaUnit> for Cow<'static, TemperatureDeltaUnit> {
+    fn from(delta: &'static TemperatureDeltaUnit) -> Self {
+        Cow::Borrowed(delta)
+    }
+}
+// A delta defined at runtime is owned by the temperature unit using it
+impl From<TemperatureDeltaUnit> for Cow<'static, TemperatureDeltaUnit> {
+    fn from(delta: TemperatureDeltaUnit) -> Self {
+        Cow::Owned(delta)
+    }
+}
+
+// The difference between two temperatures is a temperature delta
+impl<'l> Sub<Self> for Measure<'l, TemperatureUnit> {
+    type Output = Measure<'l, TemperatureDeltaUnit>;
+    fn sub(self, other: Self) -> Self::Output {
+        let unit = self.get_unit();
+        Measure::new(self.get_val() - other.get_val_as(unit), unit.get_delta())
+    }
+}
+// A temperature shifted by a delta is still a temperature
+impl<'l, 'd> Add<Measure<'d, TemperatureDeltaUnit>> for Measure<'l, TemperatureUnit> {
+    type Output = Self;
+    fn add(self, other: Measure<'d, TemperatureDeltaUnit>) -> Self {
+        let unit = self.get_unit();
+        Measure::new(self.get_val() + other.get_val_as(unit.get_delta()), unit)
+    }
+}
+impl<'l, 'd> AddAssign<Measure<'d, TemperatureDeltaUnit>> for Measure<'l, TemperatureUnit> {
+    fn add_assign(&mut self, other: Measure<'d, TemperatureDeltaUnit>) {
+        *self = *self + other;
+    }
+}
+impl<'l, 'd> Sub<Measure<'d, TemperatureDeltaUnit>> for Measure<'l, TemperatureUnit> {
+    type Output = Self;
+    fn sub(self, other: Measure<'d, TemperatureDeltaUnit>) -> Self {
+        let unit = self.get_unit();
+        Measure::new(self.get_val() - other.get_val_as(unit.get_delta()), unit)
+    }
+}
+impl<'l, 'd> SubAssign<Measure<'d, TemperatureDeltaUnit>> for Measure<'l, TemperatureUnit> {
+    fn sub_assign(&mut self, other: Measure<'d, TemperatureDeltaUnit>) {
+        *self = *self - other;
+    }
+}
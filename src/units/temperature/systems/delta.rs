@@ -0,0 +1,46 @@
+use super::super::TemperatureDeltaUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static KELVIN: TemperatureDeltaUnit = TemperatureDeltaUnit {
+    id: Cow::Borrowed("kelvin"),
+    abbr: Cow::Borrowed("K"),
+    singular: Cow::Borrowed("kelvin"),
+    plural: Cow::Borrowed("kelvins"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.0,
+};
+
+pub static CELSIUS: TemperatureDeltaUnit = TemperatureDeltaUnit {
+    id: Cow::Borrowed("degree Celsius"),
+    abbr: Cow::Borrowed("°C"),
+    singular: Cow::Borrowed("degree Celsius"),
+    plural: Cow::Borrowed("degrees Celsius"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.0,
+};
+
+pub static FAHRENHEIT: TemperatureDeltaUnit = TemperatureDeltaUnit {
+    id: Cow::Borrowed("degree Fahrenheit"),
+    abbr: Cow::Borrowed("°F"),
+    singular: Cow::Borrowed("degree Fahrenheit"),
+    plural: Cow::Borrowed("degrees Fahrenheit"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.8,
+};
+
+pub static RANKINE: TemperatureDeltaUnit = TemperatureDeltaUnit {
+    id: Cow::Borrowed("degree Rankine"),
+    abbr: Cow::Borrowed("°R"),
+    singular: Cow::Borrowed("degree Rankine"),
+    plural: Cow::Borrowed("degrees Rankine"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.8,
+};
+
+lazy_static! {
+    /// The list of Temperature difference units are stored here
+    pub static ref DELTA_LIST: UnitList<'static, TemperatureDeltaUnit> = UnitList::new(
+        "Delta",
+        vec!(&KELVIN, &CELSIUS, &FAHRENHEIT, &RANKINE,),
+    );
+}
@@ -0,0 +1,17 @@
+use super::{TemperatureDeltaUnit, TemperatureUnit};
+use crate::registry::UnitRegistry;
+/// Module with the units for temperature differences (Kelvin and such)
+pub mod delta;
+/// Module with SI units (Kelvin and Celsius)
+pub mod si;
+/// Module with the United States customary units (Fahrenheit and Rankine)
+pub mod us;
+
+lazy_static! {
+    /// Every built-in Temperature system, for looking units up by name
+    pub static ref TEMPERATURE_REGISTRY: UnitRegistry<TemperatureUnit> =
+        UnitRegistry::with_systems(&[&si::SI_TEMPERATURE_LIST, &us::US_TEMPERATURE_LIST]);
+    /// Every built-in Temperature difference system, for looking units up by name
+    pub static ref DELTA_REGISTRY: UnitRegistry<TemperatureDeltaUnit> =
+        UnitRegistry::with_systems(&[&delta::DELTA_LIST]);
+}
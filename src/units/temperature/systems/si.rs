@@ -0,0 +1,30 @@
+use super::super::TemperatureUnit;
+use super::delta;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static KELVIN: TemperatureUnit = TemperatureUnit {
+    id: Cow::Borrowed("kelvin"),
+    abbr: Cow::Borrowed("K"),
+    singular: Cow::Borrowed("kelvin"),
+    plural: Cow::Borrowed("kelvins"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.0,
+    offset: 0.0,
+    delta: Cow::Borrowed(&delta::KELVIN),
+};
+
+pub static CELSIUS: TemperatureUnit = TemperatureUnit {
+    id: Cow::Borrowed("degree Celsius"),
+    abbr: Cow::Borrowed("°C"),
+    singular: Cow::Borrowed("degree Celsius"),
+    plural: Cow::Borrowed("degrees Celsius"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.0,
+    offset: -273.15,
+    delta: Cow::Borrowed(&delta::CELSIUS),
+};
+
+lazy_static! {
+    pub static ref SI_TEMPERATURE_LIST: UnitList<'static, TemperatureUnit> =
+        UnitList::new("SI", vec!(&KELVIN, &CELSIUS,),);
+}
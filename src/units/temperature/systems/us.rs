@@ -0,0 +1,33 @@
+use super::super::TemperatureUnit;
+use super::delta;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static FAHRENHEIT: TemperatureUnit = TemperatureUnit {
+    id: Cow::Borrowed("degree Fahrenheit"),
+    abbr: Cow::Borrowed("°F"),
+    singular: Cow::Borrowed("degree Fahrenheit"),
+    plural: Cow::Borrowed("degrees Fahrenheit"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.8,
+    offset: -459.67,
+    delta: Cow::Borrowed(&delta::FAHRENHEIT),
+};
+
+pub static RANKINE: TemperatureUnit = TemperatureUnit {
+    id: Cow::Borrowed("degree Rankine"),
+    abbr: Cow::Borrowed("°R"),
+    singular: Cow::Borrowed("degree Rankine"),
+    plural: Cow::Borrowed("degrees Rankine"),
+    aliases: Cow::Borrowed(&[]),
+    in_kelvin: 1.8,
+    offset: 0.0,
+    delta: Cow::Borrowed(&delta::RANKINE),
+};
+
+lazy_static! {
+    /// The list of US Temperature units are stored here
+    pub static ref US_TEMPERATURE_LIST: UnitList<'static, TemperatureUnit> = UnitList::new(
+        "US",
+        vec!(&FAHRENHEIT, &RANKINE,),
+    );
+}
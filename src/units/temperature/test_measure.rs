@@ -0,0 +1,82 @@
+use super::systems::{delta, si::*, us::*};
+use crate::base_types::UnitTrait;
+use crate::common::RoundTo;
+use crate::Measure;
+
+#[test]
+fn convert() {
+    let mut val = Measure::new(100.0, &CELSIUS);
+    assert_eq!(val.get_val_as(&KELVIN).round_to(9), 373.15);
+    assert_eq!(val.get_val_as(&FAHRENHEIT).round_to(9), 212.0);
+    assert_eq!(val.get_val_as(&RANKINE).round_to(9), 671.67);
+    val.convert_to(&FAHRENHEIT);
+    assert_eq!(val.get_val().round_to(9), 212.0);
+    assert_eq!(val.get_val_as(&CELSIUS).round_to(9), 100.0);
+    // The one place the two scales agree
+    let val = Measure::new(-40.0, &FAHRENHEIT);
+    assert_eq!(val.get_val_as(&CELSIUS).round_to(9), -40.0);
+    let val = Measure::new(0.0, &KELVIN);
+    assert_eq!(val.get_val_as(&CELSIUS).round_to(9), -273.15);
+    assert_eq!(val.get_val_as(&FAHRENHEIT).round_to(9), -459.67);
+}
+#[test]
+fn difference() {
+    let warm = Measure::new(25.0, &CELSIUS);
+    let cold = Measure::new(50.0, &FAHRENHEIT);
+    // The difference is in the unit of the left side
+    let diff = warm - cold;
+    assert_eq!(diff.get_val().round_to(9), 15.0);
+    assert_eq!(diff.get_unit().get_abbr(), "°C");
+    assert_eq!(diff.get_val_as(&delta::FAHRENHEIT).round_to(9), 27.0);
+    assert_eq!(diff.get_val_as(&delta::KELVIN).round_to(9), 15.0);
+    // Differences add like any other unit
+    let total = diff + Measure::new(9.0, &delta::FAHRENHEIT);
+    assert_eq!(total.get_val().round_to(9), 20.0);
+}
+#[test]
+fn shift() {
+    let mut val = Measure::new(20.0, &CELSIUS);
+    // A shift of 18 °F is 10 °C, not the (-7.8 °C) that 18 °F is as a reading
+    val += Measure::new(18.0, &delta::FAHRENHEIT);
+    assert_eq!(val.get_val().round_to(9), 30.0);
+    val -= Measure::new(5.0, &delta::KELVIN);
+    assert_eq!(val.get_val().round_to(9), 25.0);
+    let val = Measure::new(32.0, &FAHRENHEIT) + Measure::new(100.0, &delta::CELSIUS);
+    assert_eq!(val.get_val().round_to(9), 212.0);
+    let val = val - Measure::new(180.0, &delta::FAHRENHEIT);
+    assert_eq!(val.get_val_as(&CELSIUS).round_to(9), 0.0);
+}
+#[test]
+fn runtime_delta() {
+    use super::{TemperatureDeltaUnit, TemperatureUnit};
+    // A unit defined at runtime may bring its own delta
+    let reaumur_delta = TemperatureDeltaUnit::new("°Ré", "degree Réaumur", "degrees Réaumur", 0.8);
+    let reaumur = TemperatureUnit::new(
+        "°Ré",
+        "degree Réaumur",
+        "degrees Réaumur",
+        0.8,
+        -218.52,
+        reaumur_delta,
+    );
+    let boiling = Measure::new(80.0, &reaumur);
+    assert_eq!(boiling.get_val_as(&CELSIUS).round_to(9), 100.0);
+    let diff = boiling - Measure::new(0.0, &CELSIUS);
+    assert_eq!(diff.get_unit().get_abbr(), "°Ré");
+    assert_eq!(diff.get_val_as(&delta::KELVIN).round_to(9), 100.0);
+    // Or borrow a built-in one
+    let kelvin = TemperatureUnit::new("K", "kelvin", "kelvins", 1.0, 0.0, &delta::KELVIN);
+    assert_eq!(kelvin.get_delta().get_abbr(), "K");
+}
+#[cfg(feature = "rational")]
+#[test]
+fn exact() {
+    use crate::common::Rational;
+    use crate::ExactMeasure;
+    let val = ExactMeasure::new(Rational::from_integer(100), &CELSIUS);
+    assert_eq!(val.get_val_as(&KELVIN), Rational::new(37315, 100));
+    assert_eq!(val.get_val_as(&FAHRENHEIT), Rational::from_integer(212));
+    let val = ExactMeasure::new(Rational::new(1, 3), &FAHRENHEIT);
+    let back = ExactMeasure::new(val.get_val_as(&CELSIUS), &CELSIUS);
+    assert_eq!(back.get_val_as(&FAHRENHEIT), Rational::new(1, 3));
+}
@@ -0,0 +1,36 @@
+use super::systems::{delta::DELTA_LIST, si::*, us::*};
+use crate::base_types::UnitTrait;
+#[test]
+fn test_si_list() {
+    let res = SI_TEMPERATURE_LIST.parse_str("21.5 °C").unwrap();
+    assert_eq!(res.0, 21.5);
+    assert_eq!(res.1.get_singular(), CELSIUS.get_singular());
+    let res = SI_TEMPERATURE_LIST
+        .parse_str("21.5 degrees Celsius")
+        .unwrap();
+    assert_eq!(res.0, 21.5);
+    assert_eq!(res.1.get_abbr(), CELSIUS.get_abbr());
+    let res = SI_TEMPERATURE_LIST.parse_str("300K").unwrap();
+    assert_eq!(res.0, 300.0);
+    assert_eq!(res.1.get_abbr(), KELVIN.get_abbr());
+    assert!(SI_TEMPERATURE_LIST.parse_str("70 °F").is_none());
+}
+#[test]
+fn test_us_list() {
+    let res = US_TEMPERATURE_LIST.parse_str("-40 °F").unwrap();
+    assert_eq!(res.0, -40.0);
+    assert_eq!(res.1.get_abbr(), FAHRENHEIT.get_abbr());
+    assert_eq!(res.1.offset(), FAHRENHEIT.offset());
+    let res = US_TEMPERATURE_LIST
+        .parse_str("491.67 degrees Rankine")
+        .unwrap();
+    assert_eq!(res.1.get_abbr(), RANKINE.get_abbr());
+}
+#[test]
+fn test_delta_list() {
+    let res = DELTA_LIST.parse_str("5 °C").unwrap();
+    assert_eq!(res.0, 5.0);
+    // Differences never carry an offset
+    assert_eq!(res.1.offset(), 0.0);
+    assert_eq!(res.1.in_base(), 1.0);
+}
@@ -0,0 +1,166 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use crate::Measure;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::time::Duration;
+use std::{error, fmt};
+/// The different pre-made unit systems for Time
+pub mod systems;
+#[cfg(test)]
+mod test_measure;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for a Time entry.
+///
+/// The base unit for Time is `Second`.
+pub struct TimeUnit {
+    /// Identifies this unit among the others of its kind (ex: `Julian year`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `hrs`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many of this unit does it take to make a second
+    in_second: Float,
+    /// The exact `in_second` as (numerator, denominator), when `in_second` is rounded
+    exact: Option<(i64, i64)>,
+}
+impl TimeUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `TimeUnit::new("ftn", "fortnight", "fortnights", 1.0 / 1_209_600.0)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_second: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_second,
+            exact: None,
+        }
+    }
+    /// Sets the exact `in_second` as a ratio (ex: `1 / 60` for the minute), for exact conversions
+    pub fn with_exact(mut self, numerator: i64, denominator: i64) -> Self {
+        self.exact = Some((numerator, denominator));
+        self
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `hrs` for hours)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for TimeUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_second
+    }
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        self.exact
+    }
+}
+impl LinearUnit for TimeUnit {}
+// Derive the prefixed units (ex: `ms` from `s`)
+impl PrefixableUnit for TimeUnit {
+    fn with_prefix(&self, prefix: &Prefix) -> Self {
+        Self::new(
+            prefix.prefix_abbr(&self.abbr),
+            prefix.prefix_name(&self.singular),
+            prefix.prefix_name(&self.plural),
+            prefix.prefix_in_base(self.in_second),
+        )
+        .with_id(prefix.prefix_name(&self.id))
+    }
+}
+// Register the built-in systems
+impl RegisteredUnit for TimeUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::TIME_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for TimeUnit {
+    fn eq(&self, other: &TimeUnit) -> bool {
+        self.is_identical(other)
+    }
+}
+// Allow conversion to and from the standard library Duration
+impl From<Duration> for Measure<'static, TimeUnit> {
+    /// Creates a Measure in seconds from the given Duration
+    fn from(duration: Duration) -> Self {
+        Measure::new(duration.as_secs_f64(), &systems::standard::SECOND)
+    }
+}
+impl<'l> TryFrom<Measure<'l, TimeUnit>> for Duration {
+    type Error = DurationError;
+    /// Converts the Measure into a Duration.
+    ///
+    /// Fails if the value is negative, overflows a Duration, or is not finite.
+    fn try_from(measure: Measure<'l, TimeUnit>) -> Result<Self, Self::Error> {
+        let secs = measure.get_val_as(&systems::standard::SECOND);
+        if secs.is_nan() {
+            Err(DurationError::NotANumber)
+        } else if secs < 0.0 {
+            Err(DurationError::Negative)
+        } else if secs >= u64::MAX as Float + 1.0 {
+            // Past the whole seconds a Duration can hold (this is infinity as well)
+            Err(DurationError::Overflow)
+        } else {
+            Ok(Duration::from_secs_f64(secs))
+        }
+    }
+}
+/// The reason a Time Measure cannot be converted into a Duration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationError {
+    /// The time is before zero (ex: `-5 s`)
+    Negative,
+    /// The time is longer than a Duration can hold
+    Overflow,
+    /// The time is NaN
+    NotANumber,
+}
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationError::Negative => write!(f, "a duration cannot be negative"),
+            DurationError::Overflow => write!(f, "the time is too long for a duration"),
+            DurationError::NotANumber => write!(f, "the time is not a number"),
+        }
+    }
+}
+impl error::Error for DurationError {}
@@ -0,0 +1,21 @@
+use super::super::TimeUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+/// The Julian year of exactly 365.25 days
+pub static JULIAN_YEAR: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("Julian year"),
+    abbr: Cow::Borrowed("a"),
+    singular: Cow::Borrowed("Julian year"),
+    plural: Cow::Borrowed("Julian years"),
+    aliases: Cow::Borrowed(&[]),
+    in_second: 1.0 / 31557600.0,
+    exact: Some((1, 31557600)),
+};
+
+lazy_static! {
+    /// The list of astronomical Time units are stored here
+    pub static ref ASTRONOMICAL_LIST: UnitList<'static, TimeUnit> = UnitList::new(
+        "Astronomical",
+        vec!(&JULIAN_YEAR,),
+    );
+}
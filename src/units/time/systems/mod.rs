@@ -0,0 +1,15 @@
+use super::TimeUnit;
+use crate::registry::UnitRegistry;
+/// Module with astronomical units (Julian Year)
+pub mod astronomical;
+/// Module with the everyday units (Second, Hour and such)
+pub mod standard;
+
+lazy_static! {
+    /// Every built-in Time system, for looking units up by name
+    pub static ref TIME_REGISTRY: UnitRegistry<TimeUnit> = UnitRegistry::with_systems(&[
+        &standard::TIME_LIST,
+        &astronomical::ASTRONOMICAL_LIST,
+        &standard::SI_PREFIXED_TIME_LIST.list(),
+    ]);
+}
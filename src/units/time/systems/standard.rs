@@ -0,0 +1,73 @@
+use super::super::TimeUnit;
+use crate::prefix::PrefixedUnits;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static MILLISECOND: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("millisecond"),
+    abbr: Cow::Borrowed("ms"),
+    singular: Cow::Borrowed("millisecond"),
+    plural: Cow::Borrowed("milliseconds"),
+    aliases: Cow::Borrowed(&[]),
+    in_second: 1000.0,
+    exact: None,
+};
+
+pub static SECOND: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("second"),
+    abbr: Cow::Borrowed("s"),
+    singular: Cow::Borrowed("second"),
+    plural: Cow::Borrowed("seconds"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("sec"), Cow::Borrowed("secs")]),
+    in_second: 1.0,
+    exact: None,
+};
+
+pub static MINUTE: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("minute"),
+    abbr: Cow::Borrowed("min"),
+    singular: Cow::Borrowed("minute"),
+    plural: Cow::Borrowed("minutes"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("mins")]),
+    in_second: 1.0 / 60.0,
+    exact: Some((1, 60)),
+};
+
+pub static HOUR: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("hour"),
+    abbr: Cow::Borrowed("h"),
+    singular: Cow::Borrowed("hour"),
+    plural: Cow::Borrowed("hours"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("hr"), Cow::Borrowed("hrs")]),
+    in_second: 1.0 / 3600.0,
+    exact: Some((1, 3600)),
+};
+
+pub static DAY: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("day"),
+    abbr: Cow::Borrowed("d"),
+    singular: Cow::Borrowed("day"),
+    plural: Cow::Borrowed("days"),
+    aliases: Cow::Borrowed(&[]),
+    in_second: 1.0 / 86400.0,
+    exact: Some((1, 86400)),
+};
+
+pub static WEEK: TimeUnit = TimeUnit {
+    id: Cow::Borrowed("week"),
+    abbr: Cow::Borrowed("wk"),
+    singular: Cow::Borrowed("week"),
+    plural: Cow::Borrowed("weeks"),
+    aliases: Cow::Borrowed(&[]),
+    in_second: 1.0 / 604800.0,
+    exact: Some((1, 604800)),
+};
+
+lazy_static! {
+    /// The list of everyday Time units are stored here
+    pub static ref TIME_LIST: UnitList<'static, TimeUnit> = UnitList::new(
+        "Standard",
+        vec!(&MILLISECOND, &SECOND, &MINUTE, &HOUR, &DAY, &WEEK,),
+    );
+    /// Every SI prefix of the Second (ex: `µs`), found without listing each one
+    pub static ref SI_PREFIXED_TIME_LIST: PrefixedUnits<TimeUnit> = PrefixedUnits::new(&SECOND);
+}
@@ -0,0 +1,53 @@
+use super::systems::{astronomical::*, standard::*};
+use super::DurationError;
+use super::TimeUnit;
+use crate::common::RoundTo;
+use crate::Measure;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+#[test]
+fn convert() {
+    let val = Measure::new(1.0, &WEEK);
+    assert_eq!(val.get_val_as(&DAY).round_to(9), 7.0);
+    assert_eq!(val.get_val_as(&HOUR).round_to(9), 168.0);
+    assert_eq!(val.get_val_as(&SECOND).round_to(6), 604800.0);
+    let val = Measure::new(90.0, &MINUTE);
+    assert_eq!(val.get_val_as(&HOUR).round_to(9), 1.5);
+    let year = Measure::new(1.0, &JULIAN_YEAR);
+    assert_eq!(year.get_val_as(&DAY).round_to(6), 365.25);
+}
+#[test]
+fn to_duration() {
+    let val = Measure::from_literal(&TIME_LIST, "90 min").unwrap();
+    let duration = Duration::try_from(val).unwrap();
+    assert_eq!(duration, Duration::from_secs(5400));
+    let val = Measure::new(250.0, &MILLISECOND);
+    assert_eq!(Duration::try_from(val).unwrap(), Duration::from_millis(250));
+    // Negative times cannot be a Duration
+    let val = Measure::new(-1.0, &SECOND);
+    assert_eq!(Duration::try_from(val).err(), Some(DurationError::Negative));
+    // Nor can times past what it holds, or not a number
+    let val = Measure::new(1e20, &SECOND);
+    assert_eq!(Duration::try_from(val).err(), Some(DurationError::Overflow));
+    let val = Measure::new(f64::INFINITY, &SECOND);
+    assert_eq!(Duration::try_from(val).err(), Some(DurationError::Overflow));
+    let val = Measure::new(f64::NAN, &SECOND);
+    assert_eq!(
+        Duration::try_from(val).err(),
+        Some(DurationError::NotANumber)
+    );
+    let val = Measure::new(1.8e19, &SECOND);
+    assert_eq!(
+        Duration::try_from(val).unwrap().as_secs(),
+        18_000_000_000_000_000_000
+    );
+}
+#[test]
+fn from_duration() {
+    let mut val: Measure<TimeUnit> = Duration::from_millis(1500).into();
+    assert_eq!(val.get_val(), 1.5);
+    assert_eq!(val.get_val_as(&MILLISECOND), 1500.0);
+    val.convert_to(&MINUTE);
+    assert_eq!(val.get_val(), 0.025);
+}
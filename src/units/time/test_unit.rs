@@ -0,0 +1,41 @@
+use super::systems::{astronomical::*, standard::*};
+use crate::common::test_util::{run_test, TestStruct};
+#[test]
+fn test_time_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["90 min", "90 minute", "90 minutes", "90min"],
+            output: Some((90.0, &MINUTE)),
+        },
+        TestStruct {
+            inputs: vec!["250 ms", "250 milliseconds"],
+            output: Some((250.0, &MILLISECOND)),
+        },
+        TestStruct {
+            inputs: vec!["1.5 h", "1.5 hour", "1.5 hours"],
+            output: Some((1.5, &HOUR)),
+        },
+        TestStruct {
+            inputs: vec!["2 wk", "2 weeks"],
+            output: Some((2.0, &WEEK)),
+        },
+        // The Julian year is kept out of the everyday list
+        TestStruct {
+            inputs: vec!["2 a", "2 Julian years", "2 m"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&TIME_LIST, val);
+    }
+}
+#[test]
+fn test_astronomical_list() {
+    let test_list = vec![TestStruct {
+        inputs: vec!["4 a"],
+        output: Some((4.0, &JULIAN_YEAR)),
+    }];
+    for val in test_list {
+        run_test(&ASTRONOMICAL_LIST, val);
+    }
+}
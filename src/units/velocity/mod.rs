@@ -0,0 +1,102 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use std::borrow::Cow;
+/// The different pre-made unit systems for Velocity
+pub mod systems;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for a Velocity entry.
+///
+/// The base unit for Velocity is `Metre per Second`.
+pub struct VelocityUnit {
+    /// Identifies this unit among the others of its kind (ex: `mile per hour`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `kph`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many of this unit does it take to make a metre per second
+    in_metre_per_second: Float,
+    /// The exact `in_metre_per_second` as (numerator, denominator), when `in_metre_per_second` is rounded
+    exact: Option<(i64, i64)>,
+}
+impl VelocityUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `VelocityUnit::new("kn", "knot", "knots", 3600.0 / 1852.0)`
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_metre_per_second: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_metre_per_second,
+            exact: None,
+        }
+    }
+    /// Sets the exact `in_metre_per_second` as a ratio (ex: `3125 / 1397` for the mile per hour), for exact conversions
+    pub fn with_exact(mut self, numerator: i64, denominator: i64) -> Self {
+        self.exact = Some((numerator, denominator));
+        self
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `kph` for kilometres per hour)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for VelocityUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_metre_per_second
+    }
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        self.exact
+    }
+}
+impl LinearUnit for VelocityUnit {}
+// Register the built-in systems
+impl RegisteredUnit for VelocityUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::VELOCITY_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for VelocityUnit {
+    fn eq(&self, other: &VelocityUnit) -> bool {
+        self.is_identical(other)
+    }
+}
@@ -0,0 +1,14 @@
+use super::VelocityUnit;
+use crate::registry::UnitRegistry;
+/// Module with SI units (Metre per Second and such)
+pub mod si;
+/// Module with the United States customary units (Mile per Hour and such)
+pub mod us;
+
+lazy_static! {
+    /// Every built-in Velocity system, for looking units up by name
+    pub static ref VELOCITY_REGISTRY: UnitRegistry<VelocityUnit> = UnitRegistry::with_systems(&[
+        &si::SI_VELOCITY_LIST,
+        &us::US_VELOCITY_LIST,
+    ]);
+}
@@ -0,0 +1,27 @@
+use super::super::VelocityUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static METRE_PER_SECOND: VelocityUnit = VelocityUnit {
+    id: Cow::Borrowed("metre per second"),
+    abbr: Cow::Borrowed("m/s"),
+    singular: Cow::Borrowed("metre per second"),
+    plural: Cow::Borrowed("metres per second"),
+    aliases: Cow::Borrowed(&[]),
+    in_metre_per_second: 1.0,
+    exact: None,
+};
+
+pub static KILOMETRE_PER_HOUR: VelocityUnit = VelocityUnit {
+    id: Cow::Borrowed("kilometre per hour"),
+    abbr: Cow::Borrowed("km/h"),
+    singular: Cow::Borrowed("kilometre per hour"),
+    plural: Cow::Borrowed("kilometres per hour"),
+    aliases: Cow::Borrowed(&[]),
+    in_metre_per_second: 3.6,
+    exact: None,
+};
+
+lazy_static! {
+    pub static ref SI_VELOCITY_LIST: UnitList<'static, VelocityUnit> =
+        UnitList::new("SI", vec!(&METRE_PER_SECOND, &KILOMETRE_PER_HOUR,),);
+}
@@ -0,0 +1,30 @@
+use super::super::VelocityUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static FOOT_PER_SECOND: VelocityUnit = VelocityUnit {
+    id: Cow::Borrowed("foot per second"),
+    abbr: Cow::Borrowed("ft/s"),
+    singular: Cow::Borrowed("foot per second"),
+    plural: Cow::Borrowed("feet per second"),
+    aliases: Cow::Borrowed(&[]),
+    in_metre_per_second: 1.0 / 0.3048,
+    exact: Some((1250, 381)),
+};
+
+pub static MILE_PER_HOUR: VelocityUnit = VelocityUnit {
+    id: Cow::Borrowed("mile per hour"),
+    abbr: Cow::Borrowed("mph"),
+    singular: Cow::Borrowed("mile per hour"),
+    plural: Cow::Borrowed("miles per hour"),
+    aliases: Cow::Borrowed(&[]),
+    in_metre_per_second: 1.0 / 0.44704,
+    exact: Some((3125, 1397)),
+};
+
+lazy_static! {
+    /// The list of US Velocity units are stored here
+    pub static ref US_VELOCITY_LIST: UnitList<'static, VelocityUnit> = UnitList::new(
+        "US",
+        vec!(&FOOT_PER_SECOND, &MILE_PER_HOUR,),
+    );
+}
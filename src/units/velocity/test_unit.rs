@@ -0,0 +1,24 @@
+use super::systems::{si::*, us::*};
+use crate::base_types::UnitTrait;
+use crate::common::RoundTo;
+use crate::Measure;
+#[test]
+fn test_lists() {
+    let res = SI_VELOCITY_LIST.parse_str("88 km/h").unwrap();
+    assert_eq!(res.0, 88.0);
+    assert_eq!(res.1.get_abbr(), KILOMETRE_PER_HOUR.get_abbr());
+    let res = SI_VELOCITY_LIST.parse_str("3 metres per second").unwrap();
+    assert_eq!(res.0, 3.0);
+    assert_eq!(res.1.get_abbr(), METRE_PER_SECOND.get_abbr());
+    let res = US_VELOCITY_LIST.parse_str("55 mph").unwrap();
+    assert_eq!(res.1.get_abbr(), MILE_PER_HOUR.get_abbr());
+    assert!(US_VELOCITY_LIST.parse_str("55 km/h").is_none());
+}
+#[test]
+fn convert() {
+    let val = Measure::new(36.0, &KILOMETRE_PER_HOUR);
+    assert_eq!(val.get_val_as(&METRE_PER_SECOND).round_to(9), 10.0);
+    let val = Measure::new(60.0, &MILE_PER_HOUR);
+    assert_eq!(val.get_val_as(&FOOT_PER_SECOND).round_to(5), 88.0);
+    assert_eq!(val.get_val_as(&KILOMETRE_PER_HOUR).round_to(5), 96.56064);
+}
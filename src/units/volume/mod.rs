@@ -0,0 +1,116 @@
+use crate::registry::{RegisteredUnit, UnitRegistry};
+use crate::unit_creation::*;
+use std::borrow::Cow;
+/// The different pre-made unit systems for Volume
+pub mod systems;
+#[cfg(test)]
+mod test_measure;
+#[cfg(test)]
+mod test_unit;
+/// The struct that specifies the unit for a Volume entry.
+///
+/// The base unit for Volume is `Litre`.
+pub struct VolumeUnit {
+    /// Identifies this unit among the others of its kind (ex: `imperial gallon`); `==` compares only this
+    id: Cow<'static, str>,
+    /// The abbreviation for this unit
+    abbr: Cow<'static, str>,
+    /// Full name of this unit (singular)
+    singular: Cow<'static, str>,
+    /// Plural name of this unit
+    plural: Cow<'static, str>,
+    /// Other spellings accepted when parsing (ex: `liter`)
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    /// How many of this unit does it take to make a litre
+    in_litre: Float,
+    /// The exact `in_litre` as (numerator, denominator), when `in_litre` is rounded
+    exact: Option<(i64, i64)>,
+}
+impl VolumeUnit {
+    /// Generates a new unit with the given info.
+    ///
+    /// EX: `VolumeUnit::new("bbl", "barrel", "barrels", 1.0 / 158.987294928)` for oil barrels
+    pub fn new(
+        abbr: impl Into<Cow<'static, str>>,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+        in_litre: Float,
+    ) -> Self {
+        let singular = singular.into();
+        Self {
+            id: singular.clone(),
+            abbr: abbr.into(),
+            singular,
+            plural: plural.into(),
+            aliases: Cow::Borrowed(&[]),
+            in_litre,
+            exact: None,
+        }
+    }
+    /// Sets the exact `in_litre` as a ratio (ex: `125000000 / 473176473` for the US gallon), for exact conversions
+    pub fn with_exact(mut self, numerator: i64, denominator: i64) -> Self {
+        self.exact = Some((numerator, denominator));
+        self
+    }
+    /// Sets what identifies this unit among the others of its dimension (the singular by default).
+    ///
+    /// Units with the same id are taken as the same unit, whatever their spelling or size.
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+    /// Adds another spelling accepted when parsing (ex: `liter` for litres)
+    pub fn with_alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.to_mut().push(alias.into());
+        self
+    }
+}
+// Implement the UnitTrait
+impl UnitTrait for VolumeUnit {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+    fn get_abbr(&self) -> &str {
+        &self.abbr
+    }
+    fn get_singular(&self) -> &str {
+        &self.singular
+    }
+    fn get_plural(&self) -> &str {
+        &self.plural
+    }
+    fn get_aliases(&self) -> &[Cow<'static, str>] {
+        &self.aliases
+    }
+    fn in_base(&self) -> Float {
+        self.in_litre
+    }
+    fn in_base_ratio(&self) -> Option<(i64, i64)> {
+        self.exact
+    }
+}
+impl LinearUnit for VolumeUnit {}
+// Derive the prefixed units (ex: `mL` from `L`)
+impl PrefixableUnit for VolumeUnit {
+    fn with_prefix(&self, prefix: &Prefix) -> Self {
+        Self::new(
+            prefix.prefix_abbr(&self.abbr),
+            prefix.prefix_name(&self.singular),
+            prefix.prefix_name(&self.plural),
+            prefix.prefix_in_base(self.in_litre),
+        )
+        .with_id(prefix.prefix_name(&self.id))
+    }
+}
+// Register the built-in systems
+impl RegisteredUnit for VolumeUnit {
+    fn registry() -> &'static UnitRegistry<Self> {
+        &systems::VOLUME_REGISTRY
+    }
+}
+// Implement the equality operator (same id, see `UnitTrait::is_identical`)
+impl std::cmp::PartialEq for VolumeUnit {
+    fn eq(&self, other: &VolumeUnit) -> bool {
+        self.is_identical(other)
+    }
+}
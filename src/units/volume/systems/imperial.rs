@@ -0,0 +1,50 @@
+use super::super::VolumeUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static FLUID_OUNCE: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("imperial fluid ounce"),
+    abbr: Cow::Borrowed("fl oz"),
+    singular: Cow::Borrowed("fluid ounce"),
+    plural: Cow::Borrowed("fluid ounces"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.0284130625,
+    exact: Some((16000000, 454609)),
+};
+
+pub static PINT: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("imperial pint"),
+    abbr: Cow::Borrowed("pt"),
+    singular: Cow::Borrowed("pint"),
+    plural: Cow::Borrowed("pints"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.56826125,
+    exact: Some((800000, 454609)),
+};
+
+pub static QUART: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("imperial quart"),
+    abbr: Cow::Borrowed("qt"),
+    singular: Cow::Borrowed("quart"),
+    plural: Cow::Borrowed("quarts"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 1.1365225,
+    exact: Some((400000, 454609)),
+};
+
+pub static GALLON: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("imperial gallon"),
+    abbr: Cow::Borrowed("gal"),
+    singular: Cow::Borrowed("gallon"),
+    plural: Cow::Borrowed("gallons"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 4.54609,
+    exact: Some((100000, 454609)),
+};
+
+lazy_static! {
+    /// The list of British imperial Volume units are stored here
+    pub static ref IMPERIAL_LIST: UnitList<'static, VolumeUnit> = UnitList::new(
+        "Imperial",
+        vec!(&FLUID_OUNCE, &PINT, &QUART, &GALLON,),
+    );
+}
@@ -0,0 +1,21 @@
+use super::VolumeUnit;
+use crate::registry::UnitRegistry;
+/// Module with the British imperial units (Imperial Gallon and such)
+pub mod imperial;
+/// Module with SI units (Litre and such)
+pub mod si;
+/// Module with the United States customary dry units (Bushel and such)
+pub mod us_dry;
+/// Module with the United States customary liquid units (Gallon and such)
+pub mod us_liquid;
+
+lazy_static! {
+    /// Every built-in Volume system, for looking units up by name
+    pub static ref VOLUME_REGISTRY: UnitRegistry<VolumeUnit> = UnitRegistry::with_systems(&[
+        &si::SI_VOLUME_LIST,
+        &us_liquid::US_LIQUID_LIST,
+        &us_dry::US_DRY_LIST,
+        &imperial::IMPERIAL_LIST,
+        &si::SI_PREFIXED_VOLUME_LIST.list(),
+    ]);
+}
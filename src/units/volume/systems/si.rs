@@ -0,0 +1,40 @@
+use super::super::VolumeUnit;
+use crate::prefix::PrefixedUnits;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static MILLILITRE: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("millilitre"),
+    abbr: Cow::Borrowed("mL"),
+    singular: Cow::Borrowed("millilitre"),
+    plural: Cow::Borrowed("millilitres"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1000.0,
+    exact: None,
+};
+
+pub static LITRE: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("litre"),
+    abbr: Cow::Borrowed("L"),
+    singular: Cow::Borrowed("litre"),
+    plural: Cow::Borrowed("litres"),
+    aliases: Cow::Borrowed(&[Cow::Borrowed("liter"), Cow::Borrowed("liters")]),
+    in_litre: 1.0,
+    exact: None,
+};
+
+pub static CUBIC_METRE: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("cubic metre"),
+    abbr: Cow::Borrowed("m³"),
+    singular: Cow::Borrowed("cubic metre"),
+    plural: Cow::Borrowed("cubic metres"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 0.001,
+    exact: None,
+};
+
+lazy_static! {
+    pub static ref SI_VOLUME_LIST: UnitList<'static, VolumeUnit> =
+        UnitList::new("SI", vec!(&MILLILITRE, &LITRE, &CUBIC_METRE,),);
+    /// Every SI prefix of the Litre (ex: `µL`), found without listing each one
+    pub static ref SI_PREFIXED_VOLUME_LIST: PrefixedUnits<VolumeUnit> = PrefixedUnits::new(&LITRE);
+}
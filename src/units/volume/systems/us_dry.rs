@@ -0,0 +1,50 @@
+use super::super::VolumeUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static DRY_PINT: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("dry pint"),
+    abbr: Cow::Borrowed("dry pt"),
+    singular: Cow::Borrowed("dry pint"),
+    plural: Cow::Borrowed("dry pints"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.5506104713575,
+    exact: Some((400000000000, 220244188543)),
+};
+
+pub static DRY_QUART: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("dry quart"),
+    abbr: Cow::Borrowed("dry qt"),
+    singular: Cow::Borrowed("dry quart"),
+    plural: Cow::Borrowed("dry quarts"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 1.101220942715,
+    exact: Some((200000000000, 220244188543)),
+};
+
+pub static PECK: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("peck"),
+    abbr: Cow::Borrowed("pk"),
+    singular: Cow::Borrowed("peck"),
+    plural: Cow::Borrowed("pecks"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 8.80976754172,
+    exact: Some((25000000000, 220244188543)),
+};
+
+pub static BUSHEL: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("bushel"),
+    abbr: Cow::Borrowed("bu"),
+    singular: Cow::Borrowed("bushel"),
+    plural: Cow::Borrowed("bushels"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 35.23907016688,
+    exact: Some((6250000000, 220244188543)),
+};
+
+lazy_static! {
+    /// The list of US dry Volume units are stored here
+    pub static ref US_DRY_LIST: UnitList<'static, VolumeUnit> = UnitList::new(
+        "US Dry",
+        vec!(&DRY_PINT, &DRY_QUART, &PECK, &BUSHEL,),
+    );
+}
@@ -0,0 +1,60 @@
+use super::super::VolumeUnit;
+use crate::unit_creation::UnitList;
+use std::borrow::Cow;
+pub static FLUID_OUNCE: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("US fluid ounce"),
+    abbr: Cow::Borrowed("fl oz"),
+    singular: Cow::Borrowed("fluid ounce"),
+    plural: Cow::Borrowed("fluid ounces"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.0295735295625,
+    exact: Some((16000000000, 473176473)),
+};
+
+pub static CUP: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("US cup"),
+    abbr: Cow::Borrowed("c"),
+    singular: Cow::Borrowed("cup"),
+    plural: Cow::Borrowed("cups"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.2365882365,
+    exact: Some((2000000000, 473176473)),
+};
+
+pub static PINT: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("US pint"),
+    abbr: Cow::Borrowed("pt"),
+    singular: Cow::Borrowed("pint"),
+    plural: Cow::Borrowed("pints"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.473176473,
+    exact: Some((1000000000, 473176473)),
+};
+
+pub static QUART: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("US quart"),
+    abbr: Cow::Borrowed("qt"),
+    singular: Cow::Borrowed("quart"),
+    plural: Cow::Borrowed("quarts"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 0.946352946,
+    exact: Some((500000000, 473176473)),
+};
+
+pub static GALLON: VolumeUnit = VolumeUnit {
+    id: Cow::Borrowed("US gallon"),
+    abbr: Cow::Borrowed("gal"),
+    singular: Cow::Borrowed("gallon"),
+    plural: Cow::Borrowed("gallons"),
+    aliases: Cow::Borrowed(&[]),
+    in_litre: 1.0 / 3.785411784,
+    exact: Some((125000000, 473176473)),
+};
+
+lazy_static! {
+    /// The list of US liquid Volume units are stored here
+    pub static ref US_LIQUID_LIST: UnitList<'static, VolumeUnit> = UnitList::new(
+        "US Liquid",
+        vec!(&FLUID_OUNCE, &CUP, &PINT, &QUART, &GALLON,),
+    );
+}
@@ -0,0 +1,36 @@
+use super::systems::{imperial, si::*, us_dry::*, us_liquid::*};
+use crate::common::RoundTo;
+use crate::Measure;
+
+#[test]
+fn convert() {
+    let val = Measure::new(1.5, &CUBIC_METRE);
+    assert_eq!(val.get_val_as(&LITRE), 1500.0);
+    assert_eq!(val.get_val_as(&MILLILITRE), 1500000.0);
+    // US liquid conversions
+    let gallon = Measure::new(1.0, &GALLON);
+    assert_eq!(gallon.get_val_as(&LITRE).round_to(6), 3.785412);
+    assert_eq!(gallon.get_val_as(&QUART).round_to(6), 4.0);
+    assert_eq!(gallon.get_val_as(&PINT).round_to(6), 8.0);
+    assert_eq!(gallon.get_val_as(&CUP).round_to(6), 16.0);
+    assert_eq!(gallon.get_val_as(&FLUID_OUNCE).round_to(5), 128.0);
+    // US dry conversions
+    let bushel = Measure::new(1.0, &BUSHEL);
+    assert_eq!(bushel.get_val_as(&PECK).round_to(6), 4.0);
+    assert_eq!(bushel.get_val_as(&DRY_QUART).round_to(5), 32.0);
+    assert_eq!(bushel.get_val_as(&DRY_PINT).round_to(5), 64.0);
+    // Imperial conversions
+    let gallon = Measure::new(1.0, &imperial::GALLON);
+    assert_eq!(gallon.get_val_as(&LITRE).round_to(5), 4.54609);
+    assert_eq!(gallon.get_val_as(&imperial::PINT).round_to(6), 8.0);
+    assert_eq!(gallon.get_val_as(&imperial::FLUID_OUNCE).round_to(5), 160.0);
+}
+#[test]
+fn add_sub() {
+    // Mixing units: a cup and a half plus 250 millilitres
+    let mut val = Measure::new(1.5, &CUP);
+    val += Measure::new(250.0, &MILLILITRE);
+    assert_eq!(val.get_val().round_to(4), 2.5567);
+    val -= Measure::new(250.0, &MILLILITRE);
+    assert_eq!(val.get_val().round_to(6), 1.5);
+}
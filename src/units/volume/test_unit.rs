@@ -0,0 +1,89 @@
+use super::systems::{imperial, si::*, us_dry::*, us_liquid};
+use crate::common::test_util::{run_test, TestStruct};
+#[test]
+fn test_si_list() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["250 mL", "250 millilitre", "250 millilitres"],
+            output: Some((250.0, &MILLILITRE)),
+        },
+        TestStruct {
+            inputs: vec!["1.5 L", "1.5 litre", "1.5litres"],
+            output: Some((1.5, &LITRE)),
+        },
+        TestStruct {
+            inputs: vec!["2 m³", "2m³", "2 cubic metre", "2 cubic metres"],
+            output: Some((2.0, &CUBIC_METRE)),
+        },
+        // Test the thing(s) that should not be
+        TestStruct {
+            inputs: vec!["2 gal", "2 cubic  metres", "2 m²"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&SI_VOLUME_LIST, val);
+    }
+}
+#[test]
+fn test_us_lists() {
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["8 fl oz", "8 fluid ounce", "8 fluid ounces"],
+            output: Some((8.0, &us_liquid::FLUID_OUNCE)),
+        },
+        TestStruct {
+            inputs: vec!["2 c", "2 cups"],
+            output: Some((2.0, &us_liquid::CUP)),
+        },
+        TestStruct {
+            inputs: vec!["1 gal", "1 gallon"],
+            output: Some((1.0, &us_liquid::GALLON)),
+        },
+        TestStruct {
+            inputs: vec!["1 bu", "1 bushel"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&us_liquid::US_LIQUID_LIST, val);
+    }
+    let test_list = vec![
+        TestStruct {
+            inputs: vec!["3 dry pt", "3 dry pints"],
+            output: Some((3.0, &DRY_PINT)),
+        },
+        TestStruct {
+            inputs: vec!["0.5 bu", "0.5 bushels"],
+            output: Some((0.5, &BUSHEL)),
+        },
+        TestStruct {
+            inputs: vec!["3 pt"],
+            output: None,
+        },
+    ];
+    for val in test_list {
+        run_test(&US_DRY_LIST, val);
+    }
+}
+#[test]
+fn test_imperial_list() {
+    let test_list = vec![TestStruct {
+        inputs: vec!["4 pt", "4 pint", "4 pints"],
+        output: Some((4.0, &imperial::PINT)),
+    }];
+    for val in test_list {
+        run_test(&imperial::IMPERIAL_LIST, val);
+    }
+}
+#[test]
+fn test_identity() {
+    use crate::base_types::UnitTrait;
+    // The US and imperial gallons share their spelling, but not their id
+    assert_eq!(
+        us_liquid::GALLON.get_singular(),
+        imperial::GALLON.get_singular()
+    );
+    assert!(us_liquid::GALLON != imperial::GALLON);
+    assert_eq!(imperial::GALLON.get_id(), "imperial gallon");
+}